@@ -64,6 +64,42 @@ pub enum Commands {
 
         #[clap(long, value_name = "FILE")]
         user_wallet_keypair: Option<String>,
+
+        #[clap(long, value_name = "PUBKEY")]
+        referrer: Option<String>,
+
+        /// Allowlisted amount of pieces for user wallet, requires `allowlist_proof`.
+        #[clap(long, value_name = "U64")]
+        allowlist_amount: Option<u64>,
+
+        /// Allowlist Merkle proof node in hex format, could be repeated.
+        #[clap(long, value_name = "HEX", multiple_occurrences = true)]
+        allowlist_proof: Vec<String>,
+    },
+    /// Perform `BuyBatch` instruction of `mpl_membership_token` program.
+    BuyBatch {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "PUBKEY")]
+        user_token_account: String,
+
+        #[clap(long, value_name = "FILE")]
+        user_wallet_keypair: Option<String>,
+
+        #[clap(long, value_name = "U64")]
+        amount: u64,
+
+        #[clap(long, value_name = "PUBKEY")]
+        referrer: Option<String>,
+
+        /// Allowlisted amount of pieces for user wallet, requires `allowlist_proof`.
+        #[clap(long, value_name = "U64")]
+        allowlist_amount: Option<u64>,
+
+        /// Allowlist Merkle proof node in hex format, could be repeated.
+        #[clap(long, value_name = "HEX", multiple_occurrences = true)]
+        allowlist_proof: Vec<String>,
     },
     /// Perform `InitSellingResource` instruction of `mpl_membership_token` program.
     InitSellingResource {
@@ -116,5 +152,161 @@ pub enum Commands {
 
         #[clap(long, value_name = "TIMESTAMP")]
         end_date: Option<u64>,
+
+        /// Allowlist Merkle root in hex format.
+        #[clap(long, value_name = "HEX")]
+        allowlist_root: Option<String>,
+
+        #[clap(long, value_name = "U16", default_value_t = 0)]
+        referral_fee_basis_points: u16,
+
+        /// Make price decay from `price` to this floor price, requires `decay_duration`.
+        #[clap(long, value_name = "F64", requires = "decay_duration")]
+        floor_price: Option<f64>,
+
+        /// Dutch auction duration in seconds, requires `floor_price`.
+        #[clap(long, value_name = "U64", requires = "floor_price")]
+        decay_duration: Option<u64>,
+
+        /// Dutch auction price step in seconds, price decays linearly if not set.
+        #[clap(long, value_name = "U64", requires = "floor_price")]
+        step_interval: Option<u64>,
+
+        /// Treasury vesting duration in seconds since `start_date`.
+        #[clap(long, value_name = "U64")]
+        vesting_duration: Option<u64>,
+
+        /// Treasury vesting cliff in seconds since `start_date`.
+        #[clap(long, value_name = "U64", default_value_t = 0)]
+        vesting_cliff_duration: u64,
+    },
+    /// Perform `SuspendMarket` instruction of `mpl_membership_token` program.
+    SuspendMarket {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+    },
+    /// Perform `ResumeMarket` instruction of `mpl_membership_token` program.
+    ResumeMarket {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+    },
+    /// Perform `ChangePrice` instruction of `mpl_membership_token` program.
+    ChangePrice {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "F64")]
+        price: f64,
+
+        /// Scheduled price in `START_DATE:PRICE` format, could be repeated.
+        #[clap(long, value_name = "TIMESTAMP:F64", multiple_occurrences = true)]
+        price_tier: Vec<String>,
+    },
+    /// Perform `ChangeReferrers` instruction of `mpl_membership_token` program.
+    ChangeReferrers {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        /// Approved referral fee receiver, could be repeated. Omit to disable referrals.
+        #[clap(long, value_name = "PUBKEY", multiple_occurrences = true)]
+        referrer: Vec<String>,
+    },
+    /// Perform `Withdraw` instruction of `mpl_membership_token` program.
+    Withdraw {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "PUBKEY")]
+        destination: String,
+    },
+    /// Perform `CloseMarket` instruction of `mpl_membership_token` program.
+    CloseMarket {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+    },
+    /// Perform `FinalizeMarket` instruction of `mpl_membership_token` program.
+    FinalizeMarket {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        /// `Market` owner keypair, required to finalize `Market` before `end_date`.
+        #[clap(long, value_name = "FILE")]
+        authority_keypair: Option<String>,
+    },
+    /// Perform `ExtendEndDate` instruction of `mpl_membership_token` program.
+    ExtendEndDate {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "TIMESTAMP")]
+        end_date: u64,
+    },
+    /// Perform `IncreaseMaxSupply` instruction of `mpl_membership_token` program.
+    IncreaseMaxSupply {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "FILE")]
+        selling_resource_owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "U64")]
+        max_supply: u64,
+    },
+    /// Perform `ChangeStoreAdmin` instruction of `mpl_membership_token` program.
+    ChangeStoreAdmin {
+        #[clap(long, value_name = "PUBKEY")]
+        store: String,
+
+        #[clap(long, value_name = "FILE")]
+        admin_keypair: Option<String>,
+
+        #[clap(long, value_name = "FILE")]
+        new_admin_keypair: String,
+    },
+    /// Perform `ChangeMarketOwner` instruction of `mpl_membership_token` program.
+    ChangeMarketOwner {
+        #[clap(long, value_name = "PUBKEY")]
+        market: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "FILE")]
+        new_owner_keypair: String,
+    },
+    /// Perform `ChangeSellingResourceOwner` instruction of `mpl_membership_token` program.
+    ChangeSellingResourceOwner {
+        #[clap(long, value_name = "PUBKEY")]
+        selling_resource: String,
+
+        #[clap(long, value_name = "FILE")]
+        owner_keypair: Option<String>,
+
+        #[clap(long, value_name = "FILE")]
+        new_owner_keypair: String,
     },
 }
@@ -6,6 +6,7 @@ mod utils;
 use chrono::prelude::*;
 use clap::Parser;
 use cli_args::{CliArgs, Commands};
+use mpl_membership_token::state::{DutchAuction, Vesting};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     pubkey::Pubkey,
@@ -100,6 +101,42 @@ fn main() -> Result<(), error::Error> {
                 }
             );
             println!("Market::state - {:?}", market.state);
+            println!(
+                "Market::referral_fee_basis_points - {}",
+                market.referral_fee_basis_points
+            );
+            if let Some(dutch_auction) = market.dutch_auction {
+                println!(
+                    "Market::dutch_auction - from {} to {} during {}s, step {:?}",
+                    spl_token::amount_to_ui_amount(dutch_auction.start_price, decimals),
+                    spl_token::amount_to_ui_amount(dutch_auction.floor_price, decimals),
+                    dutch_auction.decay_duration,
+                    dutch_auction.step_interval
+                );
+            }
+            if let Some(vesting) = market.vesting {
+                println!(
+                    "Market::vesting - since {}, cliff {}s, duration {}s",
+                    vesting.start_date, vesting.cliff_duration, vesting.duration
+                );
+            }
+            println!(
+                "Market::withdrawn - {}",
+                spl_token::amount_to_ui_amount(market.withdrawn, decimals)
+            );
+            if let Some(final_supply) = market.final_supply {
+                println!("Market::final_supply - {}", final_supply);
+            }
+            for tier in market.price_tiers {
+                println!(
+                    "Market::price_tier - {} since {}",
+                    spl_token::amount_to_ui_amount(tier.price, decimals),
+                    tier.start_date
+                );
+            }
+            for referrer in market.referrers {
+                println!("Market::referrer - {}", referrer);
+            }
 
             None
         }
@@ -178,6 +215,13 @@ fn main() -> Result<(), error::Error> {
             pieces_in_one_wallet,
             start_date,
             end_date,
+            allowlist_root,
+            referral_fee_basis_points,
+            floor_price,
+            decay_duration,
+            step_interval,
+            vesting_duration,
+            vesting_cliff_duration,
         } => {
             let selling_resource_owner = if let Some(owner) = selling_resource_owner_keypair {
                 read_keypair_file(&owner)?
@@ -205,6 +249,28 @@ fn main() -> Result<(), error::Error> {
 
             let decimals = utils::get_mint(&client, &mint)?.decimals;
 
+            let allowlist_root = if let Some(allowlist_root) = allowlist_root {
+                Some(utils::parse_hash(&allowlist_root)?)
+            } else {
+                None
+            };
+
+            let dutch_auction = match (floor_price, decay_duration) {
+                (Some(floor_price), Some(decay_duration)) => Some(DutchAuction {
+                    start_price: spl_token::ui_amount_to_amount(price, decimals),
+                    floor_price: spl_token::ui_amount_to_amount(floor_price, decimals),
+                    decay_duration,
+                    step_interval,
+                }),
+                _ => None,
+            };
+
+            let vesting = vesting_duration.map(|duration| Vesting {
+                start_date,
+                cliff_duration: vesting_cliff_duration,
+                duration,
+            });
+
             Some(processor::create_market(
                 &client,
                 &payer_wallet,
@@ -218,12 +284,19 @@ fn main() -> Result<(), error::Error> {
                 pieces_in_one_wallet,
                 start_date,
                 end_date,
+                allowlist_root,
+                referral_fee_basis_points,
+                dutch_auction,
+                vesting,
             )?)
         }
         Commands::Buy {
             market,
             user_token_account,
             user_wallet_keypair,
+            referrer,
+            allowlist_amount,
+            allowlist_proof,
         } => {
             let user_wallet = if let Some(keypair) = user_wallet_keypair {
                 read_keypair_file(keypair)?
@@ -231,12 +304,302 @@ fn main() -> Result<(), error::Error> {
                 utils::clone_keypair(&payer_wallet)
             };
 
+            let referrer = if let Some(referrer) = referrer {
+                Some(Pubkey::from_str(&referrer)?)
+            } else {
+                None
+            };
+
+            let allowlist_proof = utils::parse_allowlist_proof(allowlist_amount, &allowlist_proof)?;
+
             Some(processor::buy(
                 &client,
                 &payer_wallet,
                 &Pubkey::from_str(&market)?,
                 &Pubkey::from_str(&user_token_account)?,
                 &user_wallet,
+                referrer.as_ref(),
+                allowlist_proof,
+            )?)
+        }
+        Commands::BuyBatch {
+            market,
+            user_token_account,
+            user_wallet_keypair,
+            amount,
+            referrer,
+            allowlist_amount,
+            allowlist_proof,
+        } => {
+            let user_wallet = if let Some(keypair) = user_wallet_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            let referrer = if let Some(referrer) = referrer {
+                Some(Pubkey::from_str(&referrer)?)
+            } else {
+                None
+            };
+
+            let allowlist_proof = utils::parse_allowlist_proof(allowlist_amount, &allowlist_proof)?;
+
+            Some(processor::buy_batch(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &Pubkey::from_str(&user_token_account)?,
+                &user_wallet,
+                amount,
+                referrer.as_ref(),
+                allowlist_proof,
+            )?)
+        }
+        Commands::SuspendMarket {
+            market,
+            owner_keypair,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::suspend_market(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+            )?)
+        }
+        Commands::ResumeMarket {
+            market,
+            owner_keypair,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::resume_market(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+            )?)
+        }
+        Commands::ChangePrice {
+            market,
+            owner_keypair,
+            price,
+            price_tier,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            let market = Pubkey::from_str(&market)?;
+            let market_state = processor::get_account_state::<mpl_membership_token::state::Market>(
+                &client, &market,
+            )?;
+            let decimals = utils::get_mint(&client, &market_state.treasury_mint)?.decimals;
+
+            let price_tiers = price_tier
+                .iter()
+                .map(|tier| utils::parse_price_tier(tier, decimals))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Some(processor::change_price(
+                &client,
+                &payer_wallet,
+                &market,
+                &owner,
+                spl_token::ui_amount_to_amount(price, decimals),
+                price_tiers,
+            )?)
+        }
+        Commands::ChangeReferrers {
+            market,
+            owner_keypair,
+            referrer,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            let referrers = referrer
+                .iter()
+                .map(|referrer| Pubkey::from_str(referrer))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Some(processor::change_referrers(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+                referrers,
+            )?)
+        }
+        Commands::Withdraw {
+            market,
+            owner_keypair,
+            destination,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::withdraw(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+                &Pubkey::from_str(&destination)?,
+            )?)
+        }
+        Commands::CloseMarket {
+            market,
+            owner_keypair,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::close_market(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+            )?)
+        }
+        Commands::FinalizeMarket {
+            market,
+            authority_keypair,
+        } => {
+            let authority = if let Some(keypair) = authority_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::finalize_market(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &authority,
+            )?)
+        }
+        Commands::ExtendEndDate {
+            market,
+            owner_keypair,
+            end_date,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::extend_end_date(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+                end_date,
+            )?)
+        }
+        Commands::IncreaseMaxSupply {
+            market,
+            owner_keypair,
+            selling_resource_owner_keypair,
+            max_supply,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            let selling_resource_owner = if let Some(keypair) = selling_resource_owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::increase_max_supply(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+                &selling_resource_owner,
+                max_supply,
+            )?)
+        }
+        Commands::ChangeStoreAdmin {
+            store,
+            admin_keypair,
+            new_admin_keypair,
+        } => {
+            let admin = if let Some(keypair) = admin_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::change_store_admin(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&store)?,
+                &admin,
+                &read_keypair_file(new_admin_keypair)?,
+            )?)
+        }
+        Commands::ChangeMarketOwner {
+            market,
+            owner_keypair,
+            new_owner_keypair,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::change_market_owner(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&market)?,
+                &owner,
+                &read_keypair_file(new_owner_keypair)?,
+            )?)
+        }
+        Commands::ChangeSellingResourceOwner {
+            selling_resource,
+            owner_keypair,
+            new_owner_keypair,
+        } => {
+            let owner = if let Some(keypair) = owner_keypair {
+                read_keypair_file(keypair)?
+            } else {
+                utils::clone_keypair(&payer_wallet)
+            };
+
+            Some(processor::change_selling_resource_owner(
+                &client,
+                &payer_wallet,
+                &Pubkey::from_str(&selling_resource)?,
+                &owner,
+                &read_keypair_file(new_owner_keypair)?,
             )?)
         }
     };
@@ -3,10 +3,13 @@
 use super::{get_account_state, UiTransactionInfo};
 use crate::{error, utils};
 use anchor_lang::{InstructionData, ToAccountMetas};
-use mpl_membership_token::utils::{find_trade_history_address, find_vault_owner_address};
+use mpl_membership_token::{
+    state::AllowlistProof,
+    utils::{find_trade_history_address, find_vault_owner_address},
+};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
-    instruction::Instruction,
+    instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     signature::Signer,
     signer::keypair::Keypair,
@@ -43,6 +46,8 @@ pub fn buy(
     market: &Pubkey,
     user_token_account: &Pubkey,
     user_wallet: &Keypair,
+    referrer: Option<&Pubkey>,
+    allowlist_proof: Option<AllowlistProof>,
 ) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
     let market_state = get_account_state::<mpl_membership_token::state::Market>(client, market)?;
     let selling_resource_state = get_account_state::<mpl_membership_token::state::SellingResource>(
@@ -126,7 +131,7 @@ pub fn buy(
         &mpl_token_metadata::id(),
     );
 
-    let accounts = mpl_membership_token::accounts::Buy {
+    let mut accounts = mpl_membership_token::accounts::Buy {
         market: *market,
         selling_resource: market_state.selling_resource,
         user_token_account: *user_token_account,
@@ -149,9 +154,15 @@ pub fn buy(
     }
     .to_account_metas(None);
 
+    // Optional referral fee receiver
+    if let Some(referrer) = referrer {
+        accounts.push(AccountMeta::new(*referrer, false));
+    }
+
     let data = mpl_membership_token::instruction::Buy {
         _trade_history_bump: trade_history_bump,
         vault_owner_bump,
+        allowlist_proof,
     }
     .data();
 
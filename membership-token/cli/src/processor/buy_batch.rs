@@ -0,0 +1,198 @@
+//! Module provide handler for `BuyBatch` command.
+
+use super::{get_account_state, UiTransactionInfo};
+use crate::{error, utils};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_membership_token::{
+    state::AllowlistProof,
+    utils::{find_trade_history_address, find_vault_owner_address},
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Signer,
+    signer::keypair::Keypair,
+    system_program,
+    sysvar::{clock, rent},
+    transaction::Transaction,
+};
+
+/// Additional `BuyBatch` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct BuyBatchUiInfo {
+    owner: Pubkey,
+    trade_history: Pubkey,
+    new_mints: Vec<Pubkey>,
+}
+
+impl UiTransactionInfo for BuyBatchUiInfo {
+    fn print(&self) {
+        println!("BuyBatch::owner - {}", self.owner);
+        println!("BuyBatch::trade_history - {}", self.trade_history);
+        for new_mint in &self.new_mints {
+            println!("BuyBatch::new_mint - {}", new_mint);
+        }
+    }
+}
+
+pub fn buy_batch(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    user_token_account: &Pubkey,
+    user_wallet: &Keypair,
+    amount: u64,
+    referrer: Option<&Pubkey>,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let market_state = get_account_state::<mpl_membership_token::state::Market>(client, market)?;
+    let selling_resource_state = get_account_state::<mpl_membership_token::state::SellingResource>(
+        client,
+        &market_state.selling_resource,
+    )?;
+    let store = market_state.store;
+    let resource_mint = selling_resource_state.resource;
+
+    let (owner, vault_owner_bump) = find_vault_owner_address(&resource_mint, &store);
+    let (trade_history, trade_history_bump) =
+        find_trade_history_address(&user_wallet.pubkey(), market);
+
+    // Should be created
+    let (master_edition, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            resource_mint.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    // Should be created
+    let (master_edition_metadata, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            resource_mint.as_ref(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let mut accounts = mpl_membership_token::accounts::BuyBatch {
+        market: *market,
+        selling_resource: market_state.selling_resource,
+        user_token_account: *user_token_account,
+        user_wallet: user_wallet.pubkey(),
+        trade_history,
+        treasury_holder: market_state.treasury_holder,
+        master_edition,
+        vault: selling_resource_state.vault,
+        owner,
+        master_edition_metadata,
+        clock: clock::id(),
+        rent: rent::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    // Optional referral fee receiver precedes editions accounts
+    if let Some(referrer) = referrer {
+        accounts.push(AccountMeta::new(*referrer, false));
+    }
+
+    let mut new_mints = Vec::new();
+
+    for i in 0..amount {
+        let new_mint = Keypair::new();
+        utils::create_mint(client, payer, &new_mint, 0)?;
+
+        let new_mint_token_account = Keypair::new();
+        utils::create_token_account(
+            client,
+            payer,
+            &new_mint_token_account,
+            &new_mint.pubkey(),
+            &payer.pubkey(),
+        )?;
+        utils::mint_to(
+            client,
+            payer,
+            &new_mint.pubkey(),
+            &new_mint_token_account.pubkey(),
+            1,
+        )?;
+
+        let edition = selling_resource_state.supply + i;
+
+        let (edition_marker, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                resource_mint.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+                (edition / mpl_token_metadata::state::EDITION_MARKER_BIT_SIZE)
+                    .to_string()
+                    .as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint.pubkey().as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        accounts.push(AccountMeta::new(new_metadata, false));
+        accounts.push(AccountMeta::new(new_edition, false));
+        accounts.push(AccountMeta::new(new_mint.pubkey(), false));
+        accounts.push(AccountMeta::new(edition_marker, false));
+
+        new_mints.push(new_mint.pubkey());
+    }
+
+    let data = mpl_membership_token::instruction::BuyBatch {
+        _trade_history_bump: trade_history_bump,
+        vault_owner_bump,
+        allowlist_proof,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, user_wallet],
+            recent_blockhash,
+        ),
+        Box::new(BuyBatchUiInfo {
+            owner,
+            trade_history,
+            new_mints,
+        }),
+    ))
+}
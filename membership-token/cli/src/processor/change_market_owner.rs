@@ -0,0 +1,62 @@
+//! Module provide handler for `ChangeMarketOwner` command.
+
+use super::UiTransactionInfo;
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    transaction::Transaction,
+};
+
+/// Additional `ChangeMarketOwner` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct ChangeMarketOwnerUiInfo {
+    market: Pubkey,
+    new_owner: Pubkey,
+}
+
+impl UiTransactionInfo for ChangeMarketOwnerUiInfo {
+    fn print(&self) {
+        println!("ChangeMarketOwner::market - {}", self.market);
+        println!("ChangeMarketOwner::new_owner - {}", self.new_owner);
+    }
+}
+
+pub fn change_market_owner(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    owner: &Keypair,
+    new_owner: &Keypair,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let accounts = mpl_membership_token::accounts::ChangeMarketOwner {
+        market: *market,
+        owner: owner.pubkey(),
+        new_owner: new_owner.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::ChangeMarketOwner {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner, new_owner],
+            recent_blockhash,
+        ),
+        Box::new(ChangeMarketOwnerUiInfo {
+            market: *market,
+            new_owner: new_owner.pubkey(),
+        }),
+    ))
+}
@@ -0,0 +1,59 @@
+//! Module provide handler for `ChangePrice` command.
+
+use super::UiTransactionInfo;
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_membership_token::state::PriceTier;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    sysvar::clock, transaction::Transaction,
+};
+
+/// Additional `ChangePrice` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct ChangePriceUiInfo {
+    market: Pubkey,
+}
+
+impl UiTransactionInfo for ChangePriceUiInfo {
+    fn print(&self) {
+        println!("ChangePrice::market - {}", self.market);
+    }
+}
+
+pub fn change_price(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    owner: &Keypair,
+    price: u64,
+    price_tiers: Vec<PriceTier>,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let accounts = mpl_membership_token::accounts::ChangePrice {
+        market: *market,
+        owner: owner.pubkey(),
+        clock: clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::ChangePrice { price, price_tiers }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            recent_blockhash,
+        ),
+        Box::new(ChangePriceUiInfo { market: *market }),
+    ))
+}
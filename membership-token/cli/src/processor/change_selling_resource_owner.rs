@@ -0,0 +1,65 @@
+//! Module provide handler for `ChangeSellingResourceOwner` command.
+
+use super::UiTransactionInfo;
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    transaction::Transaction,
+};
+
+/// Additional `ChangeSellingResourceOwner` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct ChangeSellingResourceOwnerUiInfo {
+    selling_resource: Pubkey,
+    new_owner: Pubkey,
+}
+
+impl UiTransactionInfo for ChangeSellingResourceOwnerUiInfo {
+    fn print(&self) {
+        println!(
+            "ChangeSellingResourceOwner::selling_resource - {}",
+            self.selling_resource
+        );
+        println!("ChangeSellingResourceOwner::new_owner - {}", self.new_owner);
+    }
+}
+
+pub fn change_selling_resource_owner(
+    client: &RpcClient,
+    payer: &Keypair,
+    selling_resource: &Pubkey,
+    owner: &Keypair,
+    new_owner: &Keypair,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let accounts = mpl_membership_token::accounts::ChangeSellingResourceOwner {
+        selling_resource: *selling_resource,
+        owner: owner.pubkey(),
+        new_owner: new_owner.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::ChangeSellingResourceOwner {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner, new_owner],
+            recent_blockhash,
+        ),
+        Box::new(ChangeSellingResourceOwnerUiInfo {
+            selling_resource: *selling_resource,
+            new_owner: new_owner.pubkey(),
+        }),
+    ))
+}
@@ -0,0 +1,62 @@
+//! Module provide handler for `ChangeStoreAdmin` command.
+
+use super::UiTransactionInfo;
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    transaction::Transaction,
+};
+
+/// Additional `ChangeStoreAdmin` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct ChangeStoreAdminUiInfo {
+    store: Pubkey,
+    new_admin: Pubkey,
+}
+
+impl UiTransactionInfo for ChangeStoreAdminUiInfo {
+    fn print(&self) {
+        println!("ChangeStoreAdmin::store - {}", self.store);
+        println!("ChangeStoreAdmin::new_admin - {}", self.new_admin);
+    }
+}
+
+pub fn change_store_admin(
+    client: &RpcClient,
+    payer: &Keypair,
+    store: &Pubkey,
+    admin: &Keypair,
+    new_admin: &Keypair,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let accounts = mpl_membership_token::accounts::ChangeStoreAdmin {
+        store: *store,
+        admin: admin.pubkey(),
+        new_admin: new_admin.pubkey(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::ChangeStoreAdmin {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, admin, new_admin],
+            recent_blockhash,
+        ),
+        Box::new(ChangeStoreAdminUiInfo {
+            store: *store,
+            new_admin: new_admin.pubkey(),
+        }),
+    ))
+}
@@ -0,0 +1,77 @@
+//! Module provide handler for `CloseMarket` command.
+
+use super::{get_account_state, UiTransactionInfo};
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use mpl_membership_token::utils::find_treasury_owner_address;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    system_program, sysvar::clock, transaction::Transaction,
+};
+
+/// Additional `CloseMarket` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct CloseMarketUiInfo {
+    market: Pubkey,
+    treasury_holder: Pubkey,
+}
+
+impl UiTransactionInfo for CloseMarketUiInfo {
+    fn print(&self) {
+        println!("CloseMarket::market - {}", self.market);
+        println!("CloseMarket::treasury_holder - {}", self.treasury_holder);
+    }
+}
+
+pub fn close_market(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    owner: &Keypair,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let market_state = get_account_state::<mpl_membership_token::state::Market>(client, market)?;
+
+    let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+        &market_state.treasury_mint,
+        &market_state.selling_resource,
+        market,
+    );
+
+    let accounts = mpl_membership_token::accounts::CloseMarket {
+        market: *market,
+        owner: owner.pubkey(),
+        treasury_holder: market_state.treasury_holder,
+        treasury_owner,
+        clock: clock::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::CloseMarket {
+        treasury_owner_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            recent_blockhash,
+        ),
+        Box::new(CloseMarketUiInfo {
+            market: *market,
+            treasury_holder: market_state.treasury_holder,
+        }),
+    ))
+}
@@ -3,7 +3,10 @@
 use super::{get_account_state, UiTransactionInfo};
 use crate::{error, utils};
 use anchor_lang::{InstructionData, ToAccountMetas};
-use mpl_membership_token::utils::find_treasury_owner_address;
+use mpl_membership_token::{
+    state::{DutchAuction, Vesting},
+    utils::find_treasury_owner_address,
+};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{
     instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
@@ -39,26 +42,37 @@ pub fn create_market(
     pieces_in_one_wallet: Option<u64>,
     start_date: u64,
     end_date: Option<u64>,
+    allowlist_root: Option<[u8; 32]>,
+    referral_fee_basis_points: u16,
+    dutch_auction: Option<DutchAuction>,
+    vesting: Option<Vesting>,
 ) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let market = Keypair::new();
+
     let (treasury_owner, treasury_owner_bump) =
-        find_treasury_owner_address(&mint, selling_resource);
+        find_treasury_owner_address(&mint, selling_resource, &market.pubkey());
 
-    let treasury_holder = Keypair::new();
-    utils::create_token_account(client, payer, &treasury_holder, &mint, &treasury_owner)?;
+    // Native SOL is collected directly by `treasury_owner`
+    let treasury_holder = if *mint == spl_token::native_mint::id() {
+        treasury_owner
+    } else {
+        let treasury_holder = Keypair::new();
+        utils::create_token_account(client, payer, &treasury_holder, &mint, &treasury_owner)?;
+        treasury_holder.pubkey()
+    };
 
     let selling_resource_state = get_account_state::<mpl_membership_token::state::SellingResource>(
         client,
         selling_resource,
     )?;
 
-    let market = Keypair::new();
     let accounts = mpl_membership_token::accounts::CreateMarket {
         market: market.pubkey(),
         store: selling_resource_state.store,
         selling_resource_owner: selling_resource_owner.pubkey(),
         selling_resource: *selling_resource,
         mint: *mint,
-        treasury_holder: treasury_holder.pubkey(),
+        treasury_holder,
         owner: treasury_owner,
         system_program: system_program::id(),
     }
@@ -73,6 +87,10 @@ pub fn create_market(
         pieces_in_one_wallet,
         start_date,
         end_date,
+        allowlist_root,
+        referral_fee_basis_points,
+        dutch_auction,
+        vesting,
     }
     .data();
 
@@ -94,7 +112,7 @@ pub fn create_market(
         Box::new(CreateMarketUiInfo {
             market: market.pubkey(),
             treasury_owner,
-            treasury_holder: treasury_holder.pubkey(),
+            treasury_holder,
         }),
     ))
 }
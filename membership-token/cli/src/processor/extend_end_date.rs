@@ -0,0 +1,62 @@
+//! Module provide handler for `ExtendEndDate` command.
+
+use super::UiTransactionInfo;
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    sysvar::clock, transaction::Transaction,
+};
+
+/// Additional `ExtendEndDate` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct ExtendEndDateUiInfo {
+    market: Pubkey,
+    end_date: u64,
+}
+
+impl UiTransactionInfo for ExtendEndDateUiInfo {
+    fn print(&self) {
+        println!("ExtendEndDate::market - {}", self.market);
+        println!("ExtendEndDate::end_date - {}", self.end_date);
+    }
+}
+
+pub fn extend_end_date(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    owner: &Keypair,
+    end_date: u64,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let accounts = mpl_membership_token::accounts::ExtendEndDate {
+        market: *market,
+        owner: owner.pubkey(),
+        clock: clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::ExtendEndDate { end_date }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner],
+            recent_blockhash,
+        ),
+        Box::new(ExtendEndDateUiInfo {
+            market: *market,
+            end_date,
+        }),
+    ))
+}
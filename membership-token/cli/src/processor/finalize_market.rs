@@ -0,0 +1,59 @@
+//! Module provide handler for `FinalizeMarket` command.
+
+use super::{get_account_state, UiTransactionInfo};
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    sysvar::clock, transaction::Transaction,
+};
+
+/// Additional `FinalizeMarket` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct FinalizeMarketUiInfo {
+    market: Pubkey,
+}
+
+impl UiTransactionInfo for FinalizeMarketUiInfo {
+    fn print(&self) {
+        println!("FinalizeMarket::market - {}", self.market);
+    }
+}
+
+pub fn finalize_market(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    authority: &Keypair,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let market_state = get_account_state::<mpl_membership_token::state::Market>(client, market)?;
+
+    let accounts = mpl_membership_token::accounts::FinalizeMarket {
+        market: *market,
+        selling_resource: market_state.selling_resource,
+        authority: authority.pubkey(),
+        clock: clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::FinalizeMarket {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, authority],
+            recent_blockhash,
+        ),
+        Box::new(FinalizeMarketUiInfo { market: *market }),
+    ))
+}
@@ -0,0 +1,85 @@
+//! Module provide handler for `IncreaseMaxSupply` command.
+
+use super::{get_account_state, UiTransactionInfo};
+use crate::error;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, signer::keypair::Keypair,
+    sysvar::clock, transaction::Transaction,
+};
+
+/// Additional `IncreaseMaxSupply` instruction info, that need to be displayed in TUI.
+#[derive(Debug)]
+pub struct IncreaseMaxSupplyUiInfo {
+    selling_resource: Pubkey,
+    max_supply: u64,
+}
+
+impl UiTransactionInfo for IncreaseMaxSupplyUiInfo {
+    fn print(&self) {
+        println!(
+            "IncreaseMaxSupply::selling_resource - {}",
+            self.selling_resource
+        );
+        println!("IncreaseMaxSupply::max_supply - {}", self.max_supply);
+    }
+}
+
+pub fn increase_max_supply(
+    client: &RpcClient,
+    payer: &Keypair,
+    market: &Pubkey,
+    owner: &Keypair,
+    selling_resource_owner: &Keypair,
+    max_supply: u64,
+) -> Result<(Transaction, Box<dyn UiTransactionInfo>), error::Error> {
+    let market_state = get_account_state::<mpl_membership_token::state::Market>(client, market)?;
+    let selling_resource_state = get_account_state::<mpl_membership_token::state::SellingResource>(
+        client,
+        &market_state.selling_resource,
+    )?;
+
+    let (master_edition, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            selling_resource_state.resource.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let accounts = mpl_membership_token::accounts::IncreaseMaxSupply {
+        market: *market,
+        owner: owner.pubkey(),
+        selling_resource: market_state.selling_resource,
+        selling_resource_owner: selling_resource_owner.pubkey(),
+        master_edition,
+        clock: clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token::instruction::IncreaseMaxSupply { max_supply }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let recent_blockhash = client.get_latest_blockhash()?;
+
+    Ok((
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer, owner, selling_resource_owner],
+            recent_blockhash,
+        ),
+        Box::new(IncreaseMaxSupplyUiInfo {
+            selling_resource: market_state.selling_resource,
+            max_supply,
+        }),
+    ))
+}
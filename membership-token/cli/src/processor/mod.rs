@@ -1,15 +1,41 @@
 //! Module provide instructions builder for `mpl_membership_token` program.
 
 mod buy;
+mod buy_batch;
+mod change_market_owner;
+mod change_price;
+mod change_referrers;
+mod change_selling_resource_owner;
+mod change_store_admin;
+mod close_market;
 mod create_market;
 mod create_store;
+mod extend_end_date;
+mod finalize_market;
 mod get_account_state;
+mod increase_max_supply;
 mod init_selling_resource;
+mod resume_market;
+mod suspend_market;
+mod withdraw;
 pub use buy::*;
+pub use buy_batch::*;
+pub use change_market_owner::*;
+pub use change_price::*;
+pub use change_referrers::*;
+pub use change_selling_resource_owner::*;
+pub use change_store_admin::*;
+pub use close_market::*;
 pub use create_market::*;
 pub use create_store::*;
+pub use extend_end_date::*;
+pub use finalize_market::*;
 pub use get_account_state::*;
+pub use increase_max_supply::*;
 pub use init_selling_resource::*;
+pub use resume_market::*;
+pub use suspend_market::*;
+pub use withdraw::*;
 
 /// Abstract trait to print additional information in tui.
 /// Can be implemented while building instruction.
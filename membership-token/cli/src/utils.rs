@@ -129,3 +129,66 @@ pub fn mint_to(
 pub fn clone_keypair(keypair: &Keypair) -> Keypair {
     Keypair::from_bytes(&keypair.to_bytes()).unwrap()
 }
+
+/// Parse `PriceTier` from `START_DATE:PRICE` string, where `PRICE` is UI amount.
+pub fn parse_price_tier(
+    s: &str,
+    decimals: u8,
+) -> Result<mpl_membership_token::state::PriceTier, error::Error> {
+    let (start_date, price) = s
+        .split_once(':')
+        .ok_or_else(|| error::Error::DynamicError(format!("Invalid price tier: {}", s)))?;
+
+    let start_date = start_date
+        .parse::<u64>()
+        .map_err(|e| error::Error::DynamicError(e.to_string()))?;
+    let price = price
+        .parse::<f64>()
+        .map_err(|e| error::Error::DynamicError(e.to_string()))?;
+
+    Ok(mpl_membership_token::state::PriceTier {
+        start_date,
+        price: spl_token::ui_amount_to_amount(price, decimals),
+    })
+}
+
+/// Parse 32-byte hash from hex string, optionally prefixed with `0x`.
+pub fn parse_hash(s: &str) -> Result<[u8; 32], error::Error> {
+    let hex = s.strip_prefix("0x").unwrap_or(s);
+
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(error::Error::DynamicError(format!("Invalid hash: {}", s)));
+    }
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|e| error::Error::DynamicError(e.to_string()))?;
+    }
+
+    Ok(hash)
+}
+
+/// Build `AllowlistProof` from allowlisted amount and hex encoded proof nodes.
+pub fn parse_allowlist_proof(
+    amount: Option<u64>,
+    proof: &[String],
+) -> Result<Option<mpl_membership_token::state::AllowlistProof>, error::Error> {
+    let amount = match amount {
+        Some(amount) => amount,
+        None if proof.is_empty() => return Ok(None),
+        None => {
+            return Err(error::Error::DynamicError(String::from(
+                "Allowlist proof requires allowlist amount",
+            )))
+        }
+    };
+
+    Ok(Some(mpl_membership_token::state::AllowlistProof {
+        amount,
+        proof: proof
+            .iter()
+            .map(|node| parse_hash(node))
+            .collect::<Result<Vec<_>, _>>()?,
+    }))
+}
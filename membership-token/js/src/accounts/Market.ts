@@ -21,6 +21,14 @@ export type MarketAccountDataArgs = {
   startDate: beet.bignum;
   endDate: beet.COption<beet.bignum>;
   state: definedTypes.MarketState;
+  allowlistRoot: beet.COption<number[]>;
+  priceTiers: definedTypes.PriceTier[];
+  referralFeeBasisPoints: number;
+  dutchAuction: beet.COption<definedTypes.DutchAuction>;
+  vesting: beet.COption<definedTypes.Vesting>;
+  withdrawn: beet.bignum;
+  finalSupply: beet.COption<beet.bignum>;
+  referrers: web3.PublicKey[];
 };
 
 const marketAccountDiscriminator = [219, 190, 213, 55, 0, 227, 198, 154];
@@ -44,6 +52,14 @@ export class MarketAccountData implements MarketAccountDataArgs {
     readonly startDate: beet.bignum,
     readonly endDate: beet.COption<beet.bignum>,
     readonly state: definedTypes.MarketState,
+    readonly allowlistRoot: beet.COption<number[]>,
+    readonly priceTiers: definedTypes.PriceTier[],
+    readonly referralFeeBasisPoints: number,
+    readonly dutchAuction: beet.COption<definedTypes.DutchAuction>,
+    readonly vesting: beet.COption<definedTypes.Vesting>,
+    readonly withdrawn: beet.bignum,
+    readonly finalSupply: beet.COption<beet.bignum>,
+    readonly referrers: web3.PublicKey[],
   ) {}
 
   /**
@@ -65,6 +81,14 @@ export class MarketAccountData implements MarketAccountDataArgs {
       args.startDate,
       args.endDate,
       args.state,
+      args.allowlistRoot,
+      args.priceTiers,
+      args.referralFeeBasisPoints,
+      args.dutchAuction,
+      args.vesting,
+      args.withdrawn,
+      args.finalSupply,
+      args.referrers,
     );
   }
 
@@ -152,6 +176,14 @@ export class MarketAccountData implements MarketAccountDataArgs {
       startDate: this.startDate,
       endDate: this.endDate,
       state: this.state,
+      allowlistRoot: this.allowlistRoot,
+      priceTiers: this.priceTiers,
+      referralFeeBasisPoints: this.referralFeeBasisPoints,
+      dutchAuction: this.dutchAuction,
+      vesting: this.vesting,
+      withdrawn: this.withdrawn,
+      finalSupply: this.finalSupply,
+      referrers: this.referrers,
     };
   }
 }
@@ -178,6 +210,14 @@ const marketAccountDataStruct = new beet.FixableBeetStruct<
     ['startDate', beet.u64],
     ['endDate', beet.coption(beet.u64)],
     ['state', definedTypes.marketStateEnum],
+    ['allowlistRoot', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['priceTiers', beet.array(definedTypes.priceTierBeet)],
+    ['referralFeeBasisPoints', beet.u16],
+    ['dutchAuction', beet.coption(definedTypes.dutchAuctionBeet)],
+    ['vesting', beet.coption(definedTypes.vestingBeet)],
+    ['withdrawn', beet.u64],
+    ['finalSupply', beet.coption(beet.u64)],
+    ['referrers', beet.array(beetSolana.publicKey)],
   ],
   MarketAccountData.fromArgs,
   'MarketAccountData',
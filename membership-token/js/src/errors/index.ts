@@ -319,6 +319,355 @@ export class SupplyIsGtThanMaxSupplyError extends Error {
 createErrorFromCodeLookup.set(0x1781, () => new SupplyIsGtThanMaxSupplyError());
 createErrorFromNameLookup.set('SupplyIsGtThanMaxSupply', () => new SupplyIsGtThanMaxSupplyError());
 
+/**
+ * InvalidRemainingAccounts: 'Invalid remaining accounts provided'
+ */
+export class InvalidRemainingAccountsError extends Error {
+  readonly code: number = 0x1782;
+  readonly name: string = 'InvalidRemainingAccounts';
+  constructor() {
+    super('Invalid remaining accounts provided');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRemainingAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new InvalidRemainingAccountsError());
+createErrorFromNameLookup.set(
+  'InvalidRemainingAccounts',
+  () => new InvalidRemainingAccountsError(),
+);
+
+/**
+ * BatchIsTooBig: 'Batch size is greater than allowed'
+ */
+export class BatchIsTooBigError extends Error {
+  readonly code: number = 0x1783;
+  readonly name: string = 'BatchIsTooBig';
+  constructor() {
+    super('Batch size is greater than allowed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BatchIsTooBigError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new BatchIsTooBigError());
+createErrorFromNameLookup.set('BatchIsTooBig', () => new BatchIsTooBigError());
+
+/**
+ * MarketIsSuspended: 'Market is suspended'
+ */
+export class MarketIsSuspendedError extends Error {
+  readonly code: number = 0x1784;
+  readonly name: string = 'MarketIsSuspended';
+  constructor() {
+    super('Market is suspended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MarketIsSuspendedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new MarketIsSuspendedError());
+createErrorFromNameLookup.set('MarketIsSuspended', () => new MarketIsSuspendedError());
+
+/**
+ * MarketIsNotSuspended: 'Market is not suspended'
+ */
+export class MarketIsNotSuspendedError extends Error {
+  readonly code: number = 0x1785;
+  readonly name: string = 'MarketIsNotSuspended';
+  constructor() {
+    super('Market is not suspended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MarketIsNotSuspendedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new MarketIsNotSuspendedError());
+createErrorFromNameLookup.set('MarketIsNotSuspended', () => new MarketIsNotSuspendedError());
+
+/**
+ * AllowlistProofIsNotProvided: 'Allowlist proof is not provided'
+ */
+export class AllowlistProofIsNotProvidedError extends Error {
+  readonly code: number = 0x1786;
+  readonly name: string = 'AllowlistProofIsNotProvided';
+  constructor() {
+    super('Allowlist proof is not provided');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowlistProofIsNotProvidedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new AllowlistProofIsNotProvidedError());
+createErrorFromNameLookup.set(
+  'AllowlistProofIsNotProvided',
+  () => new AllowlistProofIsNotProvidedError(),
+);
+
+/**
+ * InvalidAllowlistProof: 'Invalid allowlist proof'
+ */
+export class InvalidAllowlistProofError extends Error {
+  readonly code: number = 0x1787;
+  readonly name: string = 'InvalidAllowlistProof';
+  constructor() {
+    super('Invalid allowlist proof');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAllowlistProofError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new InvalidAllowlistProofError());
+createErrorFromNameLookup.set('InvalidAllowlistProof', () => new InvalidAllowlistProofError());
+
+/**
+ * MarketIsImmutable: 'Market is immutable'
+ */
+export class MarketIsImmutableError extends Error {
+  readonly code: number = 0x1788;
+  readonly name: string = 'MarketIsImmutable';
+  constructor() {
+    super('Market is immutable');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MarketIsImmutableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new MarketIsImmutableError());
+createErrorFromNameLookup.set('MarketIsImmutable', () => new MarketIsImmutableError());
+
+/**
+ * PriceTiersAreTooMany: 'Too many price tiers provided'
+ */
+export class PriceTiersAreTooManyError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = 'PriceTiersAreTooMany';
+  constructor() {
+    super('Too many price tiers provided');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceTiersAreTooManyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new PriceTiersAreTooManyError());
+createErrorFromNameLookup.set('PriceTiersAreTooMany', () => new PriceTiersAreTooManyError());
+
+/**
+ * PriceTiersAreNotSorted: 'Price tiers should be sorted by start date'
+ */
+export class PriceTiersAreNotSortedError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = 'PriceTiersAreNotSorted';
+  constructor() {
+    super('Price tiers should be sorted by start date');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PriceTiersAreNotSortedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new PriceTiersAreNotSortedError());
+createErrorFromNameLookup.set('PriceTiersAreNotSorted', () => new PriceTiersAreNotSortedError());
+
+/**
+ * MarketIsNotEnded: 'Market is not ended'
+ */
+export class MarketIsNotEndedError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = 'MarketIsNotEnded';
+  constructor() {
+    super('Market is not ended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MarketIsNotEndedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new MarketIsNotEndedError());
+createErrorFromNameLookup.set('MarketIsNotEnded', () => new MarketIsNotEndedError());
+
+/**
+ * TreasuryIsNotEmpty: 'Treasury is not empty'
+ */
+export class TreasuryIsNotEmptyError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = 'TreasuryIsNotEmpty';
+  constructor() {
+    super('Treasury is not empty');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreasuryIsNotEmptyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new TreasuryIsNotEmptyError());
+createErrorFromNameLookup.set('TreasuryIsNotEmpty', () => new TreasuryIsNotEmptyError());
+
+/**
+ * ReferralFeeIsTooBig: 'Referral fee basis points are greater than 10000'
+ */
+export class ReferralFeeIsTooBigError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = 'ReferralFeeIsTooBig';
+  constructor() {
+    super('Referral fee basis points are greater than 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReferralFeeIsTooBigError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new ReferralFeeIsTooBigError());
+createErrorFromNameLookup.set('ReferralFeeIsTooBig', () => new ReferralFeeIsTooBigError());
+
+/**
+ * InvalidDutchAuction: 'Invalid dutch auction parameters'
+ */
+export class InvalidDutchAuctionError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = 'InvalidDutchAuction';
+  constructor() {
+    super('Invalid dutch auction parameters');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDutchAuctionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new InvalidDutchAuctionError());
+createErrorFromNameLookup.set('InvalidDutchAuction', () => new InvalidDutchAuctionError());
+
+/**
+ * DutchAuctionPriceIsImmutable: 'Dutch auction price could not be changed'
+ */
+export class DutchAuctionPriceIsImmutableError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = 'DutchAuctionPriceIsImmutable';
+  constructor() {
+    super('Dutch auction price could not be changed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DutchAuctionPriceIsImmutableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new DutchAuctionPriceIsImmutableError());
+createErrorFromNameLookup.set(
+  'DutchAuctionPriceIsImmutable',
+  () => new DutchAuctionPriceIsImmutableError(),
+);
+
+/**
+ * InvalidVesting: 'Invalid vesting parameters'
+ */
+export class InvalidVestingError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = 'InvalidVesting';
+  constructor() {
+    super('Invalid vesting parameters');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidVestingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new InvalidVestingError());
+createErrorFromNameLookup.set('InvalidVesting', () => new InvalidVestingError());
+
+/**
+ * TreasuryIsLocked: 'Treasury funds are locked by vesting'
+ */
+export class TreasuryIsLockedError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = 'TreasuryIsLocked';
+  constructor() {
+    super('Treasury funds are locked by vesting');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TreasuryIsLockedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new TreasuryIsLockedError());
+createErrorFromNameLookup.set('TreasuryIsLocked', () => new TreasuryIsLockedError());
+
+/**
+ * EndDateIsNotExtended: 'New end date should be later than current one'
+ */
+export class EndDateIsNotExtendedError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'EndDateIsNotExtended';
+  constructor() {
+    super('New end date should be later than current one');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EndDateIsNotExtendedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new EndDateIsNotExtendedError());
+createErrorFromNameLookup.set('EndDateIsNotExtended', () => new EndDateIsNotExtendedError());
+
+/**
+ * MaxSupplyIsNotIncreased: 'New max supply should be greater than current one'
+ */
+export class MaxSupplyIsNotIncreasedError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'MaxSupplyIsNotIncreased';
+  constructor() {
+    super('New max supply should be greater than current one');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MaxSupplyIsNotIncreasedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new MaxSupplyIsNotIncreasedError());
+createErrorFromNameLookup.set('MaxSupplyIsNotIncreased', () => new MaxSupplyIsNotIncreasedError());
+
+/**
+ * InvalidReferrer: 'Referrer is not approved by the market owner'
+ */
+export class InvalidReferrerError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'InvalidReferrer';
+  constructor() {
+    super('Referrer is not approved by the market owner');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReferrerError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new InvalidReferrerError());
+createErrorFromNameLookup.set('InvalidReferrer', () => new InvalidReferrerError());
+
+/**
+ * ReferrersAreTooMany: 'Too many referrers provided'
+ */
+export class ReferrersAreTooManyError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'ReferrersAreTooMany';
+  constructor() {
+    super('Too many referrers provided');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReferrersAreTooManyError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new ReferrersAreTooManyError());
+createErrorFromNameLookup.set('ReferrersAreTooMany', () => new ReferrersAreTooManyError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  */
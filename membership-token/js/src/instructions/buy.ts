@@ -1,14 +1,16 @@
 import * as splToken from '@solana/spl-token';
 import * as beet from '@metaplex-foundation/beet';
 import * as web3 from '@solana/web3.js';
+import * as definedTypes from '../types';
 
 import { PROGRAM_ID } from '../consts';
 
 export type BuyInstructionArgs = {
   tradeHistoryBump: number;
   vaultOwnerBump: number;
+  allowlistProof: beet.COption<definedTypes.AllowlistProof>;
 };
-const buyStruct = new beet.BeetArgsStruct<
+const buyStruct = new beet.FixableBeetArgsStruct<
   BuyInstructionArgs & {
     instructionDiscriminator: number[];
   }
@@ -17,6 +19,7 @@ const buyStruct = new beet.BeetArgsStruct<
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
     ['tradeHistoryBump', beet.u8],
     ['vaultOwnerBump', beet.u8],
+    ['allowlistProof', beet.coption(definedTypes.allowlistProofBeet)],
   ],
   'BuyInstructionArgs',
 );
@@ -89,7 +92,7 @@ export function createBuyInstruction(accounts: BuyInstructionAccounts, args: Buy
     },
     {
       pubkey: userWallet,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
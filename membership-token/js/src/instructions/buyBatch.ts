@@ -0,0 +1,156 @@
+import * as splToken from '@solana/spl-token';
+import * as beet from '@metaplex-foundation/beet';
+import * as web3 from '@solana/web3.js';
+import * as definedTypes from '../types';
+
+import { PROGRAM_ID } from '../consts';
+
+export type BuyBatchInstructionArgs = {
+  tradeHistoryBump: number;
+  vaultOwnerBump: number;
+  allowlistProof: beet.COption<definedTypes.AllowlistProof>;
+};
+const buyBatchStruct = new beet.FixableBeetArgsStruct<
+  BuyBatchInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['tradeHistoryBump', beet.u8],
+    ['vaultOwnerBump', beet.u8],
+    ['allowlistProof', beet.coption(definedTypes.allowlistProofBeet)],
+  ],
+  'BuyBatchInstructionArgs',
+);
+export type BuyBatchInstructionAccounts = {
+  market: web3.PublicKey;
+  sellingResource: web3.PublicKey;
+  userTokenAccount: web3.PublicKey;
+  userWallet: web3.PublicKey;
+  tradeHistory: web3.PublicKey;
+  treasuryHolder: web3.PublicKey;
+  masterEdition: web3.PublicKey;
+  vault: web3.PublicKey;
+  owner: web3.PublicKey;
+  masterEditionMetadata: web3.PublicKey;
+  clock: web3.PublicKey;
+  tokenMetadataProgram: web3.PublicKey;
+};
+
+const buyBatchInstructionDiscriminator = [90, 48, 179, 43, 144, 67, 176, 103];
+
+/**
+ * Creates a _BuyBatch_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createBuyBatchInstruction(
+  accounts: BuyBatchInstructionAccounts,
+  args: BuyBatchInstructionArgs,
+) {
+  const {
+    market,
+    sellingResource,
+    userTokenAccount,
+    userWallet,
+    tradeHistory,
+    treasuryHolder,
+    masterEdition,
+    vault,
+    owner,
+    masterEditionMetadata,
+    clock,
+    tokenMetadataProgram,
+  } = accounts;
+
+  const [data] = buyBatchStruct.serialize({
+    instructionDiscriminator: buyBatchInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: sellingResource,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: userTokenAccount,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: userWallet,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: tradeHistory,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: treasuryHolder,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: masterEdition,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: vault,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: masterEditionMetadata,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: web3.SYSVAR_RENT_PUBKEY,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: tokenMetadataProgram,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: splToken.TOKEN_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
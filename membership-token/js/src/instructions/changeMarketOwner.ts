@@ -0,0 +1,55 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+const changeMarketOwnerStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[];
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ChangeMarketOwnerInstructionArgs',
+);
+export type ChangeMarketOwnerInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  newOwner: web3.PublicKey;
+};
+
+const changeMarketOwnerInstructionDiscriminator = [33, 205, 210, 218, 218, 237, 194, 57];
+
+/**
+ * Creates a _ChangeMarketOwner_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ */
+export function createChangeMarketOwnerInstruction(accounts: ChangeMarketOwnerInstructionAccounts) {
+  const { market, owner, newOwner } = accounts;
+
+  const [data] = changeMarketOwnerStruct.serialize({
+    instructionDiscriminator: changeMarketOwnerInstructionDiscriminator,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: newOwner,
+      isWritable: false,
+      isSigner: true,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,71 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+import * as definedTypes from '../types';
+
+import { PROGRAM_ID } from '../consts';
+
+export type ChangePriceInstructionArgs = {
+  price: beet.bignum;
+  priceTiers: definedTypes.PriceTier[];
+};
+const changePriceStruct = new beet.FixableBeetArgsStruct<
+  ChangePriceInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['price', beet.u64],
+    ['priceTiers', beet.array(definedTypes.priceTierBeet)],
+  ],
+  'ChangePriceInstructionArgs',
+);
+export type ChangePriceInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const changePriceInstructionDiscriminator = [189, 34, 77, 73, 108, 12, 123, 222];
+
+/**
+ * Creates a _ChangePrice_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createChangePriceInstruction(
+  accounts: ChangePriceInstructionAccounts,
+  args: ChangePriceInstructionArgs,
+) {
+  const { market, owner, clock } = accounts;
+
+  const [data] = changePriceStruct.serialize({
+    instructionDiscriminator: changePriceInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,69 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+
+import { PROGRAM_ID } from '../consts';
+
+export type ChangeReferrersInstructionArgs = {
+  referrers: web3.PublicKey[];
+};
+const changeReferrersStruct = new beet.FixableBeetArgsStruct<
+  ChangeReferrersInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['referrers', beet.array(beetSolana.publicKey)],
+  ],
+  'ChangeReferrersInstructionArgs',
+);
+export type ChangeReferrersInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const changeReferrersInstructionDiscriminator = [75, 184, 153, 140, 51, 219, 133, 59];
+
+/**
+ * Creates a _ChangeReferrers_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createChangeReferrersInstruction(
+  accounts: ChangeReferrersInstructionAccounts,
+  args: ChangeReferrersInstructionArgs,
+) {
+  const { market, owner, clock } = accounts;
+
+  const [data] = changeReferrersStruct.serialize({
+    instructionDiscriminator: changeReferrersInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,55 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+const changeSellingResourceOwnerStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[];
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ChangeSellingResourceOwnerInstructionArgs',
+);
+export type ChangeSellingResourceOwnerInstructionAccounts = {
+  sellingResource: web3.PublicKey;
+  owner: web3.PublicKey;
+  newOwner: web3.PublicKey;
+};
+
+const changeSellingResourceOwnerInstructionDiscriminator = [229, 79, 114, 69, 138, 143, 171, 68];
+
+/**
+ * Creates a _ChangeSellingResourceOwner_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ */
+export function createChangeSellingResourceOwnerInstruction(accounts: ChangeSellingResourceOwnerInstructionAccounts) {
+  const { sellingResource, owner, newOwner } = accounts;
+
+  const [data] = changeSellingResourceOwnerStruct.serialize({
+    instructionDiscriminator: changeSellingResourceOwnerInstructionDiscriminator,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: sellingResource,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: newOwner,
+      isWritable: false,
+      isSigner: true,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,55 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+const changeStoreAdminStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[];
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'ChangeStoreAdminInstructionArgs',
+);
+export type ChangeStoreAdminInstructionAccounts = {
+  store: web3.PublicKey;
+  admin: web3.PublicKey;
+  newAdmin: web3.PublicKey;
+};
+
+const changeStoreAdminInstructionDiscriminator = [64, 129, 19, 90, 16, 187, 175, 103];
+
+/**
+ * Creates a _ChangeStoreAdmin_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ */
+export function createChangeStoreAdminInstruction(accounts: ChangeStoreAdminInstructionAccounts) {
+  const { store, admin, newAdmin } = accounts;
+
+  const [data] = changeStoreAdminStruct.serialize({
+    instructionDiscriminator: changeStoreAdminInstructionDiscriminator,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: store,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: admin,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: newAdmin,
+      isWritable: false,
+      isSigner: true,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
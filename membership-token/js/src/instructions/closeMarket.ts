@@ -0,0 +1,91 @@
+import * as splToken from '@solana/spl-token';
+import * as beet from '@metaplex-foundation/beet';
+import * as web3 from '@solana/web3.js';
+
+import { PROGRAM_ID } from '../consts';
+
+export type CloseMarketInstructionArgs = {
+  treasuryOwnerBump: number;
+};
+const closeMarketStruct = new beet.BeetArgsStruct<
+  CloseMarketInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['treasuryOwnerBump', beet.u8],
+  ],
+  'CloseMarketInstructionArgs',
+);
+export type CloseMarketInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  treasuryHolder: web3.PublicKey;
+  treasuryOwner: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const closeMarketInstructionDiscriminator = [88, 154, 248, 186, 48, 14, 123, 244];
+
+/**
+ * Creates a _CloseMarket_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createCloseMarketInstruction(
+  accounts: CloseMarketInstructionAccounts,
+  args: CloseMarketInstructionArgs,
+) {
+  const { market, owner, treasuryHolder, treasuryOwner, clock } = accounts;
+
+  const [data] = closeMarketStruct.serialize({
+    instructionDiscriminator: closeMarketInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: true,
+      isSigner: true,
+    },
+    {
+      pubkey: treasuryHolder,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: treasuryOwner,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: splToken.TOKEN_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
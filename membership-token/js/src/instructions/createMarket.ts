@@ -1,5 +1,6 @@
 import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as definedTypes from '../types';
 
 import { PROGRAM_ID } from '../consts';
 
@@ -12,6 +13,10 @@ export type CreateMarketInstructionArgs = {
   piecesInOneWallet: beet.COption<beet.bignum>;
   startDate: beet.bignum;
   endDate: beet.COption<beet.bignum>;
+  allowlistRoot: beet.COption<number[]>;
+  referralFeeBasisPoints: number;
+  dutchAuction: beet.COption<definedTypes.DutchAuction>;
+  vesting: beet.COption<definedTypes.Vesting>;
 };
 const createMarketStruct = new beet.FixableBeetArgsStruct<
   CreateMarketInstructionArgs & {
@@ -28,6 +33,10 @@ const createMarketStruct = new beet.FixableBeetArgsStruct<
     ['piecesInOneWallet', beet.coption(beet.u64)],
     ['startDate', beet.u64],
     ['endDate', beet.coption(beet.u64)],
+    ['allowlistRoot', beet.coption(beet.uniformFixedSizeArray(beet.u8, 32))],
+    ['referralFeeBasisPoints', beet.u16],
+    ['dutchAuction', beet.coption(definedTypes.dutchAuctionBeet)],
+    ['vesting', beet.coption(definedTypes.vestingBeet)],
   ],
   'CreateMarketInstructionArgs',
 );
@@ -93,7 +102,7 @@ export function createCreateMarketInstruction(
     },
     {
       pubkey: owner,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -0,0 +1,68 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+export type ExtendEndDateInstructionArgs = {
+  endDate: beet.bignum;
+};
+const extendEndDateStruct = new beet.BeetArgsStruct<
+  ExtendEndDateInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['endDate', beet.u64],
+  ],
+  'ExtendEndDateInstructionArgs',
+);
+export type ExtendEndDateInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const extendEndDateInstructionDiscriminator = [147, 156, 23, 165, 36, 16, 238, 106];
+
+/**
+ * Creates a _ExtendEndDate_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createExtendEndDateInstruction(
+  accounts: ExtendEndDateInstructionAccounts,
+  args: ExtendEndDateInstructionArgs,
+) {
+  const { market, owner, clock } = accounts;
+
+  const [data] = extendEndDateStruct.serialize({
+    instructionDiscriminator: extendEndDateInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -0,0 +1,61 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+const finalizeMarketStruct = new beet.BeetArgsStruct<{
+  instructionDiscriminator: number[];
+}>(
+  [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
+  'FinalizeMarketInstructionArgs',
+);
+export type FinalizeMarketInstructionAccounts = {
+  market: web3.PublicKey;
+  sellingResource: web3.PublicKey;
+  authority: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const finalizeMarketInstructionDiscriminator = [16, 225, 38, 28, 213, 217, 1, 247];
+
+/**
+ * Creates a _FinalizeMarket_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ */
+export function createFinalizeMarketInstruction(accounts: FinalizeMarketInstructionAccounts) {
+  const { market, sellingResource, authority, clock } = accounts;
+
+  const [data] = finalizeMarketStruct.serialize({
+    instructionDiscriminator: finalizeMarketInstructionDiscriminator,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: sellingResource,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: authority,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
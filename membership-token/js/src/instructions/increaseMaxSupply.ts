@@ -0,0 +1,86 @@
+import * as web3 from '@solana/web3.js';
+import * as beet from '@metaplex-foundation/beet';
+
+import { PROGRAM_ID } from '../consts';
+
+export type IncreaseMaxSupplyInstructionArgs = {
+  maxSupply: beet.bignum;
+};
+const increaseMaxSupplyStruct = new beet.BeetArgsStruct<
+  IncreaseMaxSupplyInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['maxSupply', beet.u64],
+  ],
+  'IncreaseMaxSupplyInstructionArgs',
+);
+export type IncreaseMaxSupplyInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  sellingResource: web3.PublicKey;
+  sellingResourceOwner: web3.PublicKey;
+  masterEdition: web3.PublicKey;
+  clock: web3.PublicKey;
+};
+
+const increaseMaxSupplyInstructionDiscriminator = [136, 227, 12, 141, 72, 126, 190, 121];
+
+/**
+ * Creates a _IncreaseMaxSupply_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createIncreaseMaxSupplyInstruction(
+  accounts: IncreaseMaxSupplyInstructionAccounts,
+  args: IncreaseMaxSupplyInstructionArgs,
+) {
+  const { market, owner, sellingResource, sellingResourceOwner, masterEdition, clock } = accounts;
+
+  const [data] = increaseMaxSupplyStruct.serialize({
+    instructionDiscriminator: increaseMaxSupplyInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: sellingResource,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: sellingResourceOwner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: masterEdition,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: clock,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
@@ -1,4 +1,17 @@
 export * from './buy';
+export * from './buyBatch';
+export * from './changeMarketOwner';
+export * from './changePrice';
+export * from './changeReferrers';
+export * from './changeSellingResourceOwner';
+export * from './changeStoreAdmin';
+export * from './closeMarket';
 export * from './createMarket';
 export * from './createStore';
+export * from './extendEndDate';
+export * from './finalizeMarket';
+export * from './increaseMaxSupply';
 export * from './initSellingResource';
+export * from './resumeMarket';
+export * from './suspendMarket';
+export * from './withdraw';
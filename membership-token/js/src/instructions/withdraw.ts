@@ -0,0 +1,91 @@
+import * as splToken from '@solana/spl-token';
+import * as beet from '@metaplex-foundation/beet';
+import * as web3 from '@solana/web3.js';
+
+import { PROGRAM_ID } from '../consts';
+
+export type WithdrawInstructionArgs = {
+  treasuryOwnerBump: number;
+};
+const withdrawStruct = new beet.BeetArgsStruct<
+  WithdrawInstructionArgs & {
+    instructionDiscriminator: number[];
+  }
+>(
+  [
+    ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
+    ['treasuryOwnerBump', beet.u8],
+  ],
+  'WithdrawInstructionArgs',
+);
+export type WithdrawInstructionAccounts = {
+  market: web3.PublicKey;
+  owner: web3.PublicKey;
+  treasuryHolder: web3.PublicKey;
+  treasuryOwner: web3.PublicKey;
+  destination: web3.PublicKey;
+};
+
+const withdrawInstructionDiscriminator = [183, 18, 70, 156, 148, 109, 161, 34];
+
+/**
+ * Creates a _Withdraw_ instruction.
+ *
+ * @param accounts that will be accessed while the instruction is processed
+ * @param args to provide as instruction data to the program
+ */
+export function createWithdrawInstruction(
+  accounts: WithdrawInstructionAccounts,
+  args: WithdrawInstructionArgs,
+) {
+  const { market, owner, treasuryHolder, treasuryOwner, destination } = accounts;
+
+  const [data] = withdrawStruct.serialize({
+    instructionDiscriminator: withdrawInstructionDiscriminator,
+    ...args,
+  });
+  const keys: web3.AccountMeta[] = [
+    {
+      pubkey: market,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: owner,
+      isWritable: false,
+      isSigner: true,
+    },
+    {
+      pubkey: treasuryHolder,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: treasuryOwner,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: destination,
+      isWritable: true,
+      isSigner: false,
+    },
+    {
+      pubkey: web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: splToken.TOKEN_PROGRAM_ID,
+      isWritable: false,
+      isSigner: false,
+    },
+  ];
+
+  const ix = new web3.TransactionInstruction({
+    programId: new web3.PublicKey(PROGRAM_ID),
+    keys,
+    data,
+  });
+  return ix;
+}
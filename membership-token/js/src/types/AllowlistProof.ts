@@ -0,0 +1,13 @@
+import * as beet from '@metaplex-foundation/beet';
+export type AllowlistProof = {
+  amount: beet.bignum;
+  proof: number[][];
+};
+
+export const allowlistProofBeet = new beet.FixableBeetArgsStruct<AllowlistProof>(
+  [
+    ['amount', beet.u64],
+    ['proof', beet.array(beet.uniformFixedSizeArray(beet.u8, 32))],
+  ],
+  'AllowlistProof',
+);
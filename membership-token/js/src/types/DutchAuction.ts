@@ -0,0 +1,17 @@
+import * as beet from '@metaplex-foundation/beet';
+export type DutchAuction = {
+  startPrice: beet.bignum;
+  floorPrice: beet.bignum;
+  decayDuration: beet.bignum;
+  stepInterval: beet.COption<beet.bignum>;
+};
+
+export const dutchAuctionBeet = new beet.FixableBeetArgsStruct<DutchAuction>(
+  [
+    ['startPrice', beet.u64],
+    ['floorPrice', beet.u64],
+    ['decayDuration', beet.u64],
+    ['stepInterval', beet.coption(beet.u64)],
+  ],
+  'DutchAuction',
+);
@@ -4,6 +4,7 @@ export enum MarketState {
   Created,
   Active,
   Ended,
+  Suspended,
 }
 export const marketStateEnum = beet.fixedScalarEnum(MarketState) as beet.FixedSizeBeet<
   MarketState,
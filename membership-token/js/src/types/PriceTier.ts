@@ -0,0 +1,13 @@
+import * as beet from '@metaplex-foundation/beet';
+export type PriceTier = {
+  startDate: beet.bignum;
+  price: beet.bignum;
+};
+
+export const priceTierBeet = new beet.BeetArgsStruct<PriceTier>(
+  [
+    ['startDate', beet.u64],
+    ['price', beet.u64],
+  ],
+  'PriceTier',
+);
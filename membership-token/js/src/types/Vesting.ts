@@ -0,0 +1,15 @@
+import * as beet from '@metaplex-foundation/beet';
+export type Vesting = {
+  startDate: beet.bignum;
+  cliffDuration: beet.bignum;
+  duration: beet.bignum;
+};
+
+export const vestingBeet = new beet.BeetArgsStruct<Vesting>(
+  [
+    ['startDate', beet.u64],
+    ['cliffDuration', beet.u64],
+    ['duration', beet.u64],
+  ],
+  'Vesting',
+);
@@ -1,2 +1,6 @@
+export * from './AllowlistProof';
+export * from './DutchAuction';
 export * from './MarketState';
+export * from './PriceTier';
 export * from './SellingResourceState';
+export * from './Vesting';
@@ -12,9 +12,18 @@ export const findVaultOwnerAddress = (mint: PublicKey, store: PublicKey) => {
   );
 };
 
-export const findTresuryOwnerAddress = (treasuryMint: PublicKey, sellingResource: PublicKey) => {
+export const findTresuryOwnerAddress = (
+  treasuryMint: PublicKey,
+  sellingResource: PublicKey,
+  market: PublicKey,
+) => {
   return PublicKey.findProgramAddress(
-    [Buffer.from(TREASURY_OWNER_PREFIX), treasuryMint.toBuffer(), sellingResource.toBuffer()],
+    [
+      Buffer.from(TREASURY_OWNER_PREFIX),
+      treasuryMint.toBuffer(),
+      sellingResource.toBuffer(),
+      market.toBuffer(),
+    ],
     new PublicKey(PROGRAM_ID),
   );
 };
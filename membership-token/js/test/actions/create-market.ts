@@ -33,9 +33,12 @@ export const createMarket = async ({
   treasuryMint,
   params,
 }: CreateMarketParams): Promise<{ market: Keypair; treasuryHolder: Keypair }> => {
+  const market = Keypair.generate();
+
   const [treasuryOwner, treasuryOwnerBump] = await findTresuryOwnerAddress(
     treasuryMint,
     sellingResource,
+    market.publicKey,
   );
 
   logDebug(`treasuryOwner: ${treasuryOwner.toBase58()}`);
@@ -56,8 +59,6 @@ export const createMarket = async ({
   logDebug(`treasuryHolder: ${treasuryHolder.publicKey}`);
   assertConfirmedTransaction(test, createVaultRes.txConfirmed);
 
-  const market = Keypair.generate();
-
   const instruction = createCreateMarketInstruction(
     {
       market: market.publicKey,
@@ -52,6 +52,10 @@ test('buy: successful purchase for newly minted treasury mint', async (t) => {
     mutable: true,
     price: 0.001,
     piecesInOneWallet: 1,
+    allowlistRoot: null,
+    referralFeeBasisPoints: 0,
+    dutchAuction: null,
+    vesting: null,
   };
 
   const { market, treasuryHolder } = await createMarket({
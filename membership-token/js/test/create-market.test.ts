@@ -44,6 +44,10 @@ test('create-market: success', async (t) => {
     mutable: true,
     price: 0.001,
     piecesInOneWallet: 1,
+    allowlistRoot: null,
+    referralFeeBasisPoints: 0,
+    dutchAuction: null,
+    vesting: null,
   };
 
   await createMarket({
@@ -77,7 +77,7 @@ export const createBuyTransaction = async ({
       // metaplex token metadata program address
       tokenMetadataProgram: MetadataProgram.PUBKEY,
     },
-    { tradeHistoryBump, vaultOwnerBump },
+    { tradeHistoryBump, vaultOwnerBump, allowlistProof: null },
   );
 
   const tx = new Transaction();
@@ -58,4 +58,64 @@ pub enum ErrorCode {
     // 6017
     #[msg("Supply is gt than max supply")]
     SupplyIsGtThanMaxSupply,
+    // 6018
+    #[msg("Invalid remaining accounts provided")]
+    InvalidRemainingAccounts,
+    // 6019
+    #[msg("Batch size is greater than allowed")]
+    BatchIsTooBig,
+    // 6020
+    #[msg("Market is suspended")]
+    MarketIsSuspended,
+    // 6021
+    #[msg("Market is not suspended")]
+    MarketIsNotSuspended,
+    // 6022
+    #[msg("Allowlist proof is not provided")]
+    AllowlistProofIsNotProvided,
+    // 6023
+    #[msg("Invalid allowlist proof")]
+    InvalidAllowlistProof,
+    // 6024
+    #[msg("Market is immutable")]
+    MarketIsImmutable,
+    // 6025
+    #[msg("Too many price tiers provided")]
+    PriceTiersAreTooMany,
+    // 6026
+    #[msg("Price tiers should be sorted by start date")]
+    PriceTiersAreNotSorted,
+    // 6027
+    #[msg("Market is not ended")]
+    MarketIsNotEnded,
+    // 6028
+    #[msg("Treasury is not empty")]
+    TreasuryIsNotEmpty,
+    // 6029
+    #[msg("Referral fee basis points are greater than 10000")]
+    ReferralFeeIsTooBig,
+    // 6030
+    #[msg("Invalid dutch auction parameters")]
+    InvalidDutchAuction,
+    // 6031
+    #[msg("Dutch auction price could not be changed")]
+    DutchAuctionPriceIsImmutable,
+    // 6032
+    #[msg("Invalid vesting parameters")]
+    InvalidVesting,
+    // 6033
+    #[msg("Treasury funds are locked by vesting")]
+    TreasuryIsLocked,
+    // 6034
+    #[msg("New end date should be later than current one")]
+    EndDateIsNotExtended,
+    // 6035
+    #[msg("New max supply should be greater than current one")]
+    MaxSupplyIsNotIncreased,
+    // 6036
+    #[msg("Referrer is not approved by the market owner")]
+    InvalidReferrer,
+    // 6037
+    #[msg("Too many referrers provided")]
+    ReferrersAreTooMany,
 }
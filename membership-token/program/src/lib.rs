@@ -1,14 +1,20 @@
 pub mod error;
+pub mod merkle_proof;
 pub mod state;
 pub mod utils;
 
 use crate::{
     error::ErrorCode,
-    state::{Market, MarketState, SellingResource, SellingResourceState, Store, TradeHistory},
+    state::{
+        AllowlistProof, DutchAuction, Market, MarketState, PriceTier, SellingResource,
+        SellingResourceState, Store, TradeHistory, Vesting,
+    },
     utils::{
-        assert_derivation, assert_keys_equal, mpl_mint_new_edition_from_master_edition_via_token,
-        puffed_out_string, DESCRIPTION_MAX_LEN, HISTORY_PREFIX, HOLDER_PREFIX, NAME_MAX_LEN,
-        VAULT_OWNER_PREFIX,
+        assert_derivation, assert_keys_equal, assert_token_account, get_treasury_amount,
+        mpl_mint_new_edition_from_master_edition_via_token, process_purchase, puffed_out_string,
+        sys_transfer, BUY_BATCH_MAX_SIZE, DESCRIPTION_MAX_LEN, EDITION_ACCOUNTS_LEN,
+        HISTORY_PREFIX, HOLDER_PREFIX, MAX_BASIS_POINTS, MAX_PRICE_TIERS, MAX_REFERRERS,
+        NAME_MAX_LEN, VAULT_OWNER_PREFIX,
     },
 };
 use anchor_lang::{prelude::*, AnchorDeserialize, AnchorSerialize};
@@ -119,8 +125,9 @@ pub mod membership_token {
         ctx: Context<'_, '_, '_, 'info, Buy<'info>>,
         _trade_history_bump: u8,
         vault_owner_bump: u8,
+        allowlist_proof: Option<AllowlistProof>,
     ) -> ProgramResult {
-        let market = &mut ctx.accounts.market;
+        let market = &ctx.accounts.market;
         let selling_resource = &mut ctx.accounts.selling_resource;
         let user_token_account = &mut ctx.accounts.user_token_account;
         let user_wallet = &mut ctx.accounts.user_wallet;
@@ -138,46 +145,25 @@ pub mod membership_token {
         let rent = &ctx.accounts.rent;
         let token_program = &ctx.accounts.token_program;
         let system_program = &ctx.accounts.system_program;
+        // Optional account, which receives referral fee
+        let referrer = ctx.remaining_accounts.first();
 
         let metadata_mint = selling_resource.resource.clone();
         let edition = selling_resource.supply;
 
-        // Check, that `Market` is started
-        if market.start_date > clock.unix_timestamp as u64 {
-            return Err(ErrorCode::MarketIsNotStarted.into());
-        }
-
-        // Check, that `Market` is ended
-        if let Some(end_date) = market.end_date {
-            if clock.unix_timestamp as u64 > end_date {
-                return Err(ErrorCode::MarketIsEnded.into());
-            }
-        }
-
-        if trade_history.market != market.key() {
-            trade_history.market = market.key();
-        }
-
-        if trade_history.wallet != user_wallet.key() {
-            trade_history.wallet = user_wallet.key();
-        }
-
-        // Check, that user not reach buy limit
-        if let Some(pieces_in_one_wallet) = market.pieces_in_one_wallet {
-            if trade_history.already_bought == pieces_in_one_wallet {
-                return Err(ErrorCode::UserReachBuyLimit.into());
-            }
-        }
-
-        // Buy new edition
-        let cpi_program = token_program.to_account_info();
-        let cpi_accounts = token::Transfer {
-            from: user_token_account.to_account_info(),
-            to: treasury_holder.to_account_info(),
-            authority: user_wallet.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, market.price)?;
+        let supply = process_purchase(
+            market,
+            selling_resource,
+            trade_history,
+            &user_wallet.to_account_info(),
+            &user_token_account.to_account_info(),
+            &treasury_holder.to_account_info(),
+            &token_program.to_account_info(),
+            referrer,
+            &allowlist_proof,
+            1,
+            clock.unix_timestamp as u64,
+        )?;
 
         mpl_mint_new_edition_from_master_edition_via_token(
             &new_metadata.to_account_info(),
@@ -203,23 +189,99 @@ pub mod membership_token {
             ],
         )?;
 
-        trade_history.already_bought = trade_history
-            .already_bought
-            .checked_add(1)
-            .ok_or(ErrorCode::MathOverflow)?;
+        selling_resource.supply = supply;
 
-        selling_resource.supply = selling_resource
-            .supply
-            .checked_add(1)
-            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(())
+    }
 
-        // Check, that `SellingResource::max_supply` is not overflowed by `supply`
-        if let Some(max_supply) = selling_resource.max_supply {
-            if selling_resource.supply > max_supply {
-                return Err(ErrorCode::SupplyIsGtThanMaxSupply.into());
-            }
+    pub fn buy_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyBatch<'info>>,
+        _trade_history_bump: u8,
+        vault_owner_bump: u8,
+        allowlist_proof: Option<AllowlistProof>,
+    ) -> ProgramResult {
+        let market = &ctx.accounts.market;
+        let selling_resource = &mut ctx.accounts.selling_resource;
+        let user_token_account = &mut ctx.accounts.user_token_account;
+        let user_wallet = &mut ctx.accounts.user_wallet;
+        let trade_history = &mut ctx.accounts.trade_history;
+        let treasury_holder = &mut ctx.accounts.treasury_holder;
+        let master_edition = &mut ctx.accounts.master_edition;
+        let vault = &mut ctx.accounts.vault;
+        let owner = &mut ctx.accounts.owner;
+        let master_edition_metadata = &mut ctx.accounts.master_edition_metadata;
+        let clock = &ctx.accounts.clock;
+        let rent = &ctx.accounts.rent;
+        let token_program = &ctx.accounts.token_program;
+        let system_program = &ctx.accounts.system_program;
+        // Each edition is described by [new_metadata, new_edition, new_mint, edition_marker],
+        // optional referral fee receiver precedes them
+        let (referrer, editions_accounts) =
+            if ctx.remaining_accounts.len() % EDITION_ACCOUNTS_LEN == 1 {
+                (ctx.remaining_accounts.first(), &ctx.remaining_accounts[1..])
+            } else {
+                (None, ctx.remaining_accounts)
+            };
+
+        let metadata_mint = selling_resource.resource.clone();
+
+        if editions_accounts.is_empty() || editions_accounts.len() % EDITION_ACCOUNTS_LEN != 0 {
+            return Err(ErrorCode::InvalidRemainingAccounts.into());
+        }
+
+        let amount = (editions_accounts.len() / EDITION_ACCOUNTS_LEN) as u64;
+
+        if amount > BUY_BATCH_MAX_SIZE {
+            return Err(ErrorCode::BatchIsTooBig.into());
         }
 
+        let supply = process_purchase(
+            market,
+            selling_resource,
+            trade_history,
+            &user_wallet.to_account_info(),
+            &user_token_account.to_account_info(),
+            &treasury_holder.to_account_info(),
+            &token_program.to_account_info(),
+            referrer,
+            &allowlist_proof,
+            amount,
+            clock.unix_timestamp as u64,
+        )?;
+
+        for (i, edition_accounts) in editions_accounts.chunks(EDITION_ACCOUNTS_LEN).enumerate() {
+            let edition = selling_resource
+                .supply
+                .checked_add(i as u64)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            mpl_mint_new_edition_from_master_edition_via_token(
+                &edition_accounts[0],
+                &edition_accounts[1],
+                &edition_accounts[2],
+                &user_wallet.to_account_info(),
+                &user_wallet.to_account_info(),
+                &owner.to_account_info(),
+                &vault.to_account_info(),
+                &master_edition_metadata.to_account_info(),
+                &master_edition.to_account_info(),
+                &metadata_mint,
+                &edition_accounts[3],
+                &token_program.to_account_info(),
+                &system_program.to_account_info(),
+                &rent.to_account_info(),
+                edition,
+                &[
+                    VAULT_OWNER_PREFIX.as_bytes(),
+                    selling_resource.resource.as_ref(),
+                    selling_resource.store.as_ref(),
+                    &[vault_owner_bump],
+                ],
+            )?;
+        }
+
+        selling_resource.supply = supply;
+
         Ok(())
     }
 
@@ -233,6 +295,10 @@ pub mod membership_token {
         pieces_in_one_wallet: Option<u64>,
         start_date: u64,
         end_date: Option<u64>,
+        allowlist_root: Option<[u8; 32]>,
+        referral_fee_basis_points: u16,
+        dutch_auction: Option<DutchAuction>,
+        vesting: Option<Vesting>,
     ) -> ProgramResult {
         let market = &mut ctx.accounts.market;
         let store = &ctx.accounts.store;
@@ -258,6 +324,30 @@ pub mod membership_token {
             return Err(ErrorCode::PiecesInOneWalletIsTooMuch.into());
         }
 
+        if referral_fee_basis_points > MAX_BASIS_POINTS {
+            return Err(ErrorCode::ReferralFeeIsTooBig.into());
+        }
+
+        // Price should decay to the floor during non-empty time window
+        if let Some(dutch_auction) = &dutch_auction {
+            if dutch_auction.start_price < dutch_auction.floor_price
+                || dutch_auction.decay_duration == 0
+            {
+                return Err(ErrorCode::InvalidDutchAuction.into());
+            }
+        }
+
+        // Funds should be fully unlocked at the end of non-empty vesting period,
+        // which could not start before the sale
+        if let Some(vesting) = &vesting {
+            if vesting.duration == 0
+                || vesting.cliff_duration > vesting.duration
+                || vesting.start_date < start_date
+            {
+                return Err(ErrorCode::InvalidVesting.into());
+            }
+        }
+
         // start_date cannot be in the past
         if start_date < Clock::get().unwrap().unix_timestamp as u64 {
             return Err(ErrorCode::StartDateIsInPast.into());
@@ -271,6 +361,30 @@ pub mod membership_token {
         // Check selling resource ownership
         assert_keys_equal(selling_resource.owner, selling_resource_owner.key())?;
 
+        // Native SOL is collected directly by `treasury_owner` PDA, which should be rent exempt
+        if mint.key() == spl_token::native_mint::id() {
+            assert_keys_equal(treasury_holder.key(), owner.key())?;
+
+            let rent_exempt_lamports = Rent::get()?
+                .minimum_balance(0)
+                .saturating_sub(owner.lamports());
+
+            if rent_exempt_lamports > 0 {
+                sys_transfer(
+                    &selling_resource_owner.to_account_info(),
+                    &owner.to_account_info(),
+                    rent_exempt_lamports,
+                    &[],
+                )?;
+            }
+        } else {
+            assert_token_account(
+                &treasury_holder.to_account_info(),
+                &mint.key(),
+                &owner.key(),
+            )?;
+        }
+
         market.store = store.key();
         market.selling_resource = selling_resource.key();
         market.treasury_mint = mint.key();
@@ -285,6 +399,465 @@ pub mod membership_token {
         market.start_date = start_date;
         market.end_date = end_date;
         market.state = MarketState::Created;
+        market.allowlist_root = allowlist_root;
+        market.price_tiers = Vec::new();
+        market.referral_fee_basis_points = referral_fee_basis_points;
+        market.dutch_auction = dutch_auction;
+        market.vesting = vesting;
+        market.withdrawn = 0;
+        market.final_supply = None;
+        market.referrers = Vec::new();
+
+        emit!(MarketCreated {
+            market: market.key(),
+            store: market.store,
+            selling_resource: market.selling_resource,
+            owner: market.owner,
+            treasury_mint: market.treasury_mint,
+            price: market.price,
+            start_date: market.start_date,
+            end_date: market.end_date,
+        });
+
+        Ok(())
+    }
+
+    pub fn suspend_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, SuspendMarket<'info>>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let clock = &ctx.accounts.clock;
+
+        if market.state == MarketState::Suspended {
+            return Err(ErrorCode::MarketIsSuspended.into());
+        }
+
+        // Ended or finalized `Market` could not be suspended
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        market.state = MarketState::Suspended;
+
+        emit!(MarketSuspended {
+            market: market.key(),
+            timestamp: clock.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    pub fn resume_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResumeMarket<'info>>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let clock = &ctx.accounts.clock;
+
+        if market.state != MarketState::Suspended {
+            return Err(ErrorCode::MarketIsNotSuspended.into());
+        }
+
+        // Ended or finalized `Market` could not be resumed
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        // `Suspended` state overwrites the previous one, so it is restored from `start_date`
+        market.state = if (clock.unix_timestamp as u64) < market.start_date {
+            MarketState::Created
+        } else {
+            MarketState::Active
+        };
+
+        emit!(MarketResumed {
+            market: market.key(),
+            timestamp: clock.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    pub fn change_price<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangePrice<'info>>,
+        price: u64,
+        price_tiers: Vec<PriceTier>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let clock = &ctx.accounts.clock;
+
+        if !market.mutable {
+            return Err(ErrorCode::MarketIsImmutable.into());
+        }
+
+        if market.dutch_auction.is_some() {
+            return Err(ErrorCode::DutchAuctionPriceIsImmutable.into());
+        }
+
+        // Check, that `Market` is ended or finalized
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        if price_tiers.len() > MAX_PRICE_TIERS {
+            return Err(ErrorCode::PriceTiersAreTooMany.into());
+        }
+
+        // Tiers should be strictly sorted to have only one active tier at a time
+        if price_tiers
+            .windows(2)
+            .any(|tiers| tiers[0].start_date >= tiers[1].start_date)
+        {
+            return Err(ErrorCode::PriceTiersAreNotSorted.into());
+        }
+
+        market.price = price;
+        market.price_tiers = price_tiers;
+
+        emit!(PriceChanged {
+            market: market.key(),
+            price: market.price,
+            price_tiers: market.price_tiers.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn change_referrers<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeReferrers<'info>>,
+        referrers: Vec<Pubkey>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let clock = &ctx.accounts.clock;
+
+        // Check, that `Market` is ended or finalized
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        if referrers.len() > MAX_REFERRERS {
+            return Err(ErrorCode::ReferrersAreTooMany.into());
+        }
+
+        market.referrers = referrers;
+
+        emit!(ReferrersChanged {
+            market: market.key(),
+            referrers: market.referrers.clone(),
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+        treasury_owner_bump: u8,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let treasury_holder = &ctx.accounts.treasury_holder;
+        let treasury_owner = &ctx.accounts.treasury_owner;
+        let destination = &ctx.accounts.destination;
+        let token_program = &ctx.accounts.token_program;
+
+        let market_key = market.key();
+        let treasury_mint = market.treasury_mint;
+        let selling_resource = market.selling_resource;
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            HOLDER_PREFIX.as_bytes(),
+            treasury_mint.as_ref(),
+            selling_resource.as_ref(),
+            market_key.as_ref(),
+            &[treasury_owner_bump],
+        ]];
+
+        let treasury_amount =
+            get_treasury_amount(&treasury_holder.to_account_info(), market.is_native())?;
+
+        // Vesting unlocks part of all ever collected funds, already withdrawn funds included
+        let amount = if let Some(vesting) = &market.vesting {
+            let total = treasury_amount
+                .checked_add(market.withdrawn)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            vesting
+                .unlocked_amount(total, Clock::get()?.unix_timestamp as u64)
+                .saturating_sub(market.withdrawn)
+        } else {
+            treasury_amount
+        };
+
+        if amount == 0 && treasury_amount != 0 {
+            return Err(ErrorCode::TreasuryIsLocked.into());
+        }
+
+        market.withdrawn = market
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // Transfer all unlocked funds to the `Market` owner
+        if market.is_native() {
+            sys_transfer(
+                &treasury_owner.to_account_info(),
+                &destination.to_account_info(),
+                amount,
+                signer_seeds,
+            )?;
+        } else {
+            let cpi_program = token_program.to_account_info();
+            let cpi_accounts = token::Transfer {
+                from: treasury_holder.to_account_info(),
+                to: destination.to_account_info(),
+                authority: treasury_owner.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+            token::transfer(cpi_ctx, amount)?;
+        }
+
+        emit!(Withdrawn {
+            market: market.key(),
+            destination: destination.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseMarket<'info>>,
+        treasury_owner_bump: u8,
+    ) -> ProgramResult {
+        let market = &ctx.accounts.market;
+        let treasury_holder = &ctx.accounts.treasury_holder;
+        let treasury_owner = &ctx.accounts.treasury_owner;
+        let owner = &ctx.accounts.owner;
+        let clock = &ctx.accounts.clock;
+        let token_program = &ctx.accounts.token_program;
+
+        // `Market` without `end_date` should be finalized before closing
+        if !market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsNotEnded.into());
+        }
+
+        // Funds should be withdrawn before closing
+        if get_treasury_amount(&treasury_holder.to_account_info(), market.is_native())? != 0 {
+            return Err(ErrorCode::TreasuryIsNotEmpty.into());
+        }
+
+        emit!(MarketClosed {
+            market: market.key(),
+            owner: owner.key(),
+            timestamp: clock.unix_timestamp as u64,
+        });
+
+        let market_key = market.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            HOLDER_PREFIX.as_bytes(),
+            market.treasury_mint.as_ref(),
+            market.selling_resource.as_ref(),
+            market_key.as_ref(),
+            &[treasury_owner_bump],
+        ]];
+
+        // Native treasury is a system account, only rent exempt minimum is left after withdrawal
+        if market.is_native() {
+            sys_transfer(
+                &treasury_owner.to_account_info(),
+                &owner.to_account_info(),
+                treasury_owner.lamports(),
+                signer_seeds,
+            )?;
+
+            return Ok(());
+        }
+
+        // Return treasury holder rent to the `Market` owner
+        let cpi_program = token_program.to_account_info();
+        let cpi_accounts = token::CloseAccount {
+            account: treasury_holder.to_account_info(),
+            destination: owner.to_account_info(),
+            authority: treasury_owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+        token::close_account(cpi_ctx)?;
+
+        // `Market` account itself is closed by `close` constraint
+
+        Ok(())
+    }
+
+    pub fn finalize_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeMarket<'info>>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let selling_resource = &ctx.accounts.selling_resource;
+        let authority = &ctx.accounts.authority;
+        let clock = &ctx.accounts.clock;
+
+        if market.state == MarketState::Ended {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        // `Market` could be finalized by anyone after `end_date`. Before that only the owner
+        // could finalize `Market` without `end_date` or `Market` with all pieces sold
+        if !market.is_ended(clock.unix_timestamp as u64) {
+            let is_sold_out = selling_resource
+                .max_supply
+                .map(|max_supply| selling_resource.supply >= max_supply)
+                .unwrap_or(false);
+
+            if authority.key() != market.owner || (market.end_date.is_some() && !is_sold_out) {
+                return Err(ErrorCode::MarketIsNotEnded.into());
+            }
+        }
+
+        market.state = MarketState::Ended;
+        market.final_supply = Some(selling_resource.supply);
+
+        emit!(MarketEnded {
+            market: market.key(),
+            timestamp: clock.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    pub fn extend_end_date<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExtendEndDate<'info>>,
+        end_date: u64,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let clock = &ctx.accounts.clock;
+
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        // `Market` without `end_date` is already unlimited in time
+        match market.end_date {
+            Some(current_end_date) if end_date > current_end_date => {}
+            _ => return Err(ErrorCode::EndDateIsNotExtended.into()),
+        }
+
+        market.end_date = Some(end_date);
+
+        emit!(EndDateExtended {
+            market: market.key(),
+            end_date,
+        });
+
+        Ok(())
+    }
+
+    pub fn increase_max_supply<'info>(
+        ctx: Context<'_, '_, '_, 'info, IncreaseMaxSupply<'info>>,
+        max_supply: u64,
+    ) -> ProgramResult {
+        let market = &ctx.accounts.market;
+        let selling_resource = &mut ctx.accounts.selling_resource;
+        let selling_resource_owner = &ctx.accounts.selling_resource_owner;
+        let master_edition_info = &ctx.accounts.master_edition.to_account_info();
+        let clock = &ctx.accounts.clock;
+
+        // `SellingResource` limit is shared by all its markets, so its owner should approve it too
+        assert_keys_equal(selling_resource.owner, selling_resource_owner.key())?;
+
+        if market.is_ended(clock.unix_timestamp as u64) {
+            return Err(ErrorCode::MarketIsEnded.into());
+        }
+
+        // `SellingResource` without `max_supply` is already unlimited
+        match selling_resource.max_supply {
+            Some(current_max_supply) if max_supply > current_max_supply => {}
+            _ => return Err(ErrorCode::MaxSupplyIsNotIncreased.into()),
+        }
+
+        // Check `MasterEdition` derivation
+        assert_derivation(
+            &mpl_token_metadata::id(),
+            master_edition_info,
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+        )?;
+
+        let master_edition =
+            mpl_token_metadata::state::MasterEditionV2::from_account_info(master_edition_info)?;
+
+        // Ensure, that not yet sold pieces are under `MasterEditionV2::max_supply` bounds
+        if let Some(me_max_supply) = master_edition.max_supply {
+            let available_supply = me_max_supply
+                .checked_sub(master_edition.supply)
+                .ok_or(ErrorCode::MathOverflow)?;
+
+            if max_supply - selling_resource.supply > available_supply {
+                return Err(ErrorCode::SupplyIsGtThanAvailable.into());
+            }
+        }
+
+        selling_resource.max_supply = Some(max_supply);
+
+        emit!(MaxSupplyIncreased {
+            market: market.key(),
+            selling_resource: selling_resource.key(),
+            max_supply,
+        });
+
+        Ok(())
+    }
+
+    pub fn change_store_admin<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeStoreAdmin<'info>>,
+    ) -> ProgramResult {
+        let store = &mut ctx.accounts.store;
+        let new_admin = &ctx.accounts.new_admin;
+
+        // New admin signature proves, that `Store` is not transferred to an unusable key
+        emit!(StoreAdminChanged {
+            store: store.key(),
+            admin: store.admin,
+            new_admin: new_admin.key(),
+        });
+
+        store.admin = new_admin.key();
+
+        Ok(())
+    }
+
+    pub fn change_market_owner<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeMarketOwner<'info>>,
+    ) -> ProgramResult {
+        let market = &mut ctx.accounts.market;
+        let new_owner = &ctx.accounts.new_owner;
+
+        // New owner signature proves, that `Market` is not transferred to an unusable key
+        emit!(MarketOwnerChanged {
+            market: market.key(),
+            owner: market.owner,
+            new_owner: new_owner.key(),
+        });
+
+        market.owner = new_owner.key();
+
+        Ok(())
+    }
+
+    pub fn change_selling_resource_owner<'info>(
+        ctx: Context<'_, '_, '_, 'info, ChangeSellingResourceOwner<'info>>,
+    ) -> ProgramResult {
+        let selling_resource = &mut ctx.accounts.selling_resource;
+        let new_owner = &ctx.accounts.new_owner;
+
+        // Markets keep their own owners, only new markets and supply changes are affected
+        emit!(SellingResourceOwnerChanged {
+            selling_resource: selling_resource.key(),
+            owner: selling_resource.owner,
+            new_owner: new_owner.key(),
+        });
+
+        selling_resource.owner = new_owner.key();
 
         Ok(())
     }
@@ -325,19 +898,21 @@ pub struct CreateStore<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(trade_history_bump:u8, vault_owner_bump: u8)]
+#[instruction(trade_history_bump:u8, vault_owner_bump: u8, allowlist_proof: Option<AllowlistProof>)]
 pub struct Buy<'info> {
     #[account(has_one=treasury_holder)]
     market: Account<'info, Market>,
     #[account(mut)]
     selling_resource: Box<Account<'info, SellingResource>>,
+    // Not used for native SOL `Market`
+    #[account(mut)]
+    user_token_account: UncheckedAccount<'info>,
     #[account(mut)]
-    user_token_account: Box<Account<'info, TokenAccount>>,
     user_wallet: Signer<'info>,
-    #[account(init_if_needed, seeds=[HISTORY_PREFIX.as_bytes(), user_wallet.key().as_ref(), market.key().as_ref()], bump=trade_history_bump, payer=user_wallet)]
+    #[account(init_if_needed, seeds=[HISTORY_PREFIX.as_bytes(), user_wallet.key().as_ref(), market.key().as_ref()], bump=trade_history_bump, payer=user_wallet, space=TradeHistory::LEN)]
     trade_history: Account<'info, TradeHistory>,
     #[account(mut)]
-    treasury_holder: Box<Account<'info, TokenAccount>>,
+    treasury_holder: UncheckedAccount<'info>,
     // Will be created by `mpl_token_metadata`
     #[account(mut)]
     new_metadata: UncheckedAccount<'info>,
@@ -365,7 +940,38 @@ pub struct Buy<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(treasyry_owner_bump: u8, name: String, description: String, mutable: bool, price: u64, pieces_in_one_wallet: Option<u64>, start_date: u64, end_date: Option<u64>)]
+#[instruction(trade_history_bump:u8, vault_owner_bump: u8, allowlist_proof: Option<AllowlistProof>)]
+pub struct BuyBatch<'info> {
+    #[account(has_one=treasury_holder)]
+    market: Account<'info, Market>,
+    #[account(mut)]
+    selling_resource: Box<Account<'info, SellingResource>>,
+    // Not used for native SOL `Market`
+    #[account(mut)]
+    user_token_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    user_wallet: Signer<'info>,
+    #[account(init_if_needed, seeds=[HISTORY_PREFIX.as_bytes(), user_wallet.key().as_ref(), market.key().as_ref()], bump=trade_history_bump, payer=user_wallet, space=TradeHistory::LEN)]
+    trade_history: Account<'info, TradeHistory>,
+    #[account(mut)]
+    treasury_holder: UncheckedAccount<'info>,
+    #[account(mut, owner=mpl_token_metadata::id())]
+    master_edition: UncheckedAccount<'info>,
+    #[account(mut, has_one=owner)]
+    vault: Box<Account<'info, TokenAccount>>,
+    #[account(seeds=[VAULT_OWNER_PREFIX.as_bytes(), selling_resource.resource.as_ref(), selling_resource.store.as_ref()], bump=vault_owner_bump)]
+    owner: UncheckedAccount<'info>,
+    #[account(owner=mpl_token_metadata::id())]
+    master_edition_metadata: UncheckedAccount<'info>,
+    clock: Sysvar<'info, Clock>,
+    rent: Sysvar<'info, Rent>,
+    token_metadata_program: UncheckedAccount<'info>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(treasyry_owner_bump: u8, name: String, description: String, mutable: bool, price: u64, pieces_in_one_wallet: Option<u64>, start_date: u64, end_date: Option<u64>, allowlist_root: Option<[u8; 32]>, referral_fee_basis_points: u16, dutch_auction: Option<DutchAuction>, vesting: Option<Vesting>)]
 pub struct CreateMarket<'info> {
     #[account(init, space=Market::LEN, payer=selling_resource_owner)]
     market: Box<Account<'info, Market>>,
@@ -375,9 +981,250 @@ pub struct CreateMarket<'info> {
     #[account(mut, has_one=store)]
     selling_resource: Box<Account<'info, SellingResource>>,
     mint: Box<Account<'info, Mint>>,
-    #[account(mut, has_one=owner, has_one=mint)]
-    treasury_holder: Box<Account<'info, TokenAccount>>,
-    #[account(seeds=[HOLDER_PREFIX.as_bytes(), mint.key().as_ref(), selling_resource.key().as_ref()], bump=treasyry_owner_bump)]
+    // Should be `owner` itself for native SOL `Market`
+    #[account(mut)]
+    treasury_holder: UncheckedAccount<'info>,
+    #[account(mut, seeds=[HOLDER_PREFIX.as_bytes(), mint.key().as_ref(), selling_resource.key().as_ref(), market.key().as_ref()], bump=treasyry_owner_bump)]
     owner: UncheckedAccount<'info>,
     system_program: Program<'info, System>,
 }
+
+#[derive(Accounts)]
+pub struct SuspendMarket<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct ResumeMarket<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(price: u64, price_tiers: Vec<PriceTier>)]
+pub struct ChangePrice<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(referrers: Vec<Pubkey>)]
+pub struct ChangeReferrers<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+#[instruction(treasury_owner_bump: u8)]
+pub struct Withdraw<'info> {
+    #[account(mut, has_one=owner, has_one=treasury_holder, has_one=treasury_owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    #[account(mut)]
+    treasury_holder: UncheckedAccount<'info>,
+    #[account(mut, seeds=[HOLDER_PREFIX.as_bytes(), market.treasury_mint.as_ref(), market.selling_resource.as_ref(), market.key().as_ref()], bump=treasury_owner_bump)]
+    treasury_owner: UncheckedAccount<'info>,
+    // Token account of `treasury_mint` or any system account for native SOL `Market`
+    #[account(mut)]
+    destination: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+    token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(treasury_owner_bump: u8)]
+pub struct CloseMarket<'info> {
+    #[account(mut, has_one=owner, has_one=treasury_holder, has_one=treasury_owner, close=owner)]
+    market: Account<'info, Market>,
+    #[account(mut)]
+    owner: Signer<'info>,
+    #[account(mut)]
+    treasury_holder: UncheckedAccount<'info>,
+    #[account(mut, seeds=[HOLDER_PREFIX.as_bytes(), market.treasury_mint.as_ref(), market.selling_resource.as_ref(), market.key().as_ref()], bump=treasury_owner_bump)]
+    treasury_owner: UncheckedAccount<'info>,
+    clock: Sysvar<'info, Clock>,
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeMarket<'info> {
+    #[account(mut, has_one=selling_resource)]
+    market: Account<'info, Market>,
+    selling_resource: Account<'info, SellingResource>,
+    authority: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendEndDate<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct IncreaseMaxSupply<'info> {
+    #[account(has_one=owner, has_one=selling_resource)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    #[account(mut)]
+    selling_resource: Account<'info, SellingResource>,
+    selling_resource_owner: Signer<'info>,
+    #[account(owner=mpl_token_metadata::id())]
+    master_edition: UncheckedAccount<'info>,
+    clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeStoreAdmin<'info> {
+    #[account(mut, has_one=admin)]
+    store: Account<'info, Store>,
+    admin: Signer<'info>,
+    new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeMarketOwner<'info> {
+    #[account(mut, has_one=owner)]
+    market: Account<'info, Market>,
+    owner: Signer<'info>,
+    new_owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ChangeSellingResourceOwner<'info> {
+    #[account(mut, has_one=owner)]
+    selling_resource: Account<'info, SellingResource>,
+    owner: Signer<'info>,
+    new_owner: Signer<'info>,
+}
+
+/// Emitted when new `Market` is created.
+#[event]
+pub struct MarketCreated {
+    pub market: Pubkey,
+    pub store: Pubkey,
+    pub selling_resource: Pubkey,
+    pub owner: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub price: u64,
+    pub start_date: u64,
+    pub end_date: Option<u64>,
+}
+
+/// Emitted when editions are bought by `Buy` or `BuyBatch`.
+#[event]
+pub struct PurchaseCompleted {
+    pub market: Pubkey,
+    pub wallet: Pubkey,
+    /// Number of bought editions.
+    pub amount: u64,
+    /// Total paid price, including referral fee.
+    pub price: u64,
+    pub referrer: Option<Pubkey>,
+    pub referral_fee: u64,
+    /// `SellingResource` supply after the purchase.
+    pub supply: u64,
+}
+
+/// Emitted when `Market` is finalized.
+#[event]
+pub struct MarketEnded {
+    pub market: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Emitted when ended `Market` is closed by the owner.
+#[event]
+pub struct MarketClosed {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Emitted when `Market` is suspended.
+#[event]
+pub struct MarketSuspended {
+    pub market: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Emitted when suspended `Market` is resumed.
+#[event]
+pub struct MarketResumed {
+    pub market: Pubkey,
+    pub timestamp: u64,
+}
+
+/// Emitted when `Market` price or price tiers are changed.
+#[event]
+pub struct PriceChanged {
+    pub market: Pubkey,
+    pub price: u64,
+    pub price_tiers: Vec<PriceTier>,
+}
+
+/// Emitted when `Market` referrers are changed.
+#[event]
+pub struct ReferrersChanged {
+    pub market: Pubkey,
+    pub referrers: Vec<Pubkey>,
+}
+
+/// Emitted when treasury funds are withdrawn.
+#[event]
+pub struct Withdrawn {
+    pub market: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when `Market::end_date` is extended.
+#[event]
+pub struct EndDateExtended {
+    pub market: Pubkey,
+    pub end_date: u64,
+}
+
+/// Emitted when `SellingResource::max_supply` is increased.
+#[event]
+pub struct MaxSupplyIncreased {
+    pub market: Pubkey,
+    pub selling_resource: Pubkey,
+    pub max_supply: u64,
+}
+
+/// Emitted when `Store::admin` is changed.
+#[event]
+pub struct StoreAdminChanged {
+    pub store: Pubkey,
+    pub admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+/// Emitted when `Market::owner` is changed.
+#[event]
+pub struct MarketOwnerChanged {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+/// Emitted when `SellingResource::owner` is changed.
+#[event]
+pub struct SellingResourceOwnerChanged {
+    pub selling_resource: Pubkey,
+    pub owner: Pubkey,
+    pub new_owner: Pubkey,
+}
@@ -1,6 +1,6 @@
 //! Module provide program defined state
 
-use crate::utils::{DESCRIPTION_DEFAULT_SIZE, NAME_DEFAULT_SIZE};
+use crate::utils::{DESCRIPTION_DEFAULT_SIZE, MAX_PRICE_TIERS, MAX_REFERRERS, NAME_DEFAULT_SIZE};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -45,6 +45,80 @@ pub enum MarketState {
     Created,
     Active,
     Ended,
+    Suspended,
+}
+
+/// Price, which is applied to `Market` since `start_date`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PriceTier {
+    pub start_date: u64,
+    pub price: u64,
+}
+
+impl PriceTier {
+    pub const LEN: usize = 8 + 8;
+}
+
+/// Declining price parameters. Price decays from `start_price` to `floor_price`
+/// during `decay_duration` seconds since `Market::start_date`,
+/// linearly or by `step_interval` seconds steps
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DutchAuction {
+    pub start_price: u64,
+    pub floor_price: u64,
+    pub decay_duration: u64,
+    pub step_interval: Option<u64>,
+}
+
+impl DutchAuction {
+    pub const LEN: usize = 8 + 8 + 8 + 9;
+
+    /// Return decayed price after `elapsed` seconds since auction start
+    pub fn price_after(&self, elapsed: u64) -> u64 {
+        // Stepwise decay changes price only at the end of each step
+        let elapsed = match self.step_interval {
+            Some(step_interval) if step_interval > 0 => elapsed - elapsed % step_interval,
+            _ => elapsed,
+        };
+
+        if elapsed >= self.decay_duration {
+            return self.floor_price;
+        }
+
+        let decay = (self.start_price - self.floor_price) as u128 * elapsed as u128
+            / self.decay_duration as u128;
+
+        self.start_price - decay as u64
+    }
+}
+
+/// Treasury unlock schedule. Nothing is unlocked before `start_date + cliff_duration`,
+/// then funds unlock linearly until `start_date + duration`.
+/// Single cliff is described by `cliff_duration == duration`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Vesting {
+    pub start_date: u64,
+    pub cliff_duration: u64,
+    pub duration: u64,
+}
+
+impl Vesting {
+    pub const LEN: usize = 8 + 8 + 8;
+
+    /// Return unlocked part of `total` at `timestamp`
+    pub fn unlocked_amount(&self, total: u64, timestamp: u64) -> u64 {
+        let elapsed = timestamp.saturating_sub(self.start_date);
+
+        if elapsed < self.cliff_duration {
+            return 0;
+        }
+
+        if elapsed >= self.duration {
+            return total;
+        }
+
+        (total as u128 * elapsed as u128 / self.duration as u128) as u64
+    }
 }
 
 #[account]
@@ -63,6 +137,19 @@ pub struct Market {
     pub start_date: u64,
     pub end_date: Option<u64>,
     pub state: MarketState,
+    pub allowlist_root: Option<[u8; 32]>,
+    pub price_tiers: Vec<PriceTier>,
+    /// Part of the price paid to the approved referrer passed to `Buy`
+    pub referral_fee_basis_points: u16,
+    pub dutch_auction: Option<DutchAuction>,
+    pub vesting: Option<Vesting>,
+    /// Total amount withdrawn from treasury
+    pub withdrawn: u64,
+    /// `SellingResource` supply at the moment of `Market` finalization
+    pub final_supply: Option<u64>,
+    /// Referral fee receivers approved by the owner. Unapproved referrers are rejected,
+    /// otherwise any buyer could pass a second own wallet and take the fee as a discount
+    pub referrers: Vec<Pubkey>,
 }
 
 impl Market {
@@ -80,7 +167,60 @@ impl Market {
         + 9
         + 8
         + 9
-        + 1;
+        + 1
+        + 33
+        + 4
+        + PriceTier::LEN * MAX_PRICE_TIERS
+        + 2
+        + 1
+        + DutchAuction::LEN
+        + 1
+        + Vesting::LEN
+        + 8
+        + 9
+        + 4
+        + 32 * MAX_REFERRERS;
+
+    /// Check, that `Market` collects native SOL instead of SPL tokens
+    pub fn is_native(&self) -> bool {
+        self.treasury_mint == spl_token::native_mint::id()
+    }
+
+    /// Check, that `Market` is ended at `timestamp`
+    pub fn is_ended(&self, timestamp: u64) -> bool {
+        if self.state == MarketState::Ended {
+            return true;
+        }
+
+        if let Some(end_date) = self.end_date {
+            timestamp > end_date
+        } else {
+            false
+        }
+    }
+
+    /// Return price, which is active at `timestamp`.
+    /// Dutch auction price has priority, otherwise
+    /// `price_tiers` are sorted by `start_date`, so the latest started tier wins.
+    pub fn price_at(&self, timestamp: u64) -> u64 {
+        if let Some(dutch_auction) = &self.dutch_auction {
+            return dutch_auction.price_after(timestamp.saturating_sub(self.start_date));
+        }
+
+        self.price_tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.start_date <= timestamp)
+            .map(|tier| tier.price)
+            .unwrap_or(self.price)
+    }
+}
+
+/// Proof, that wallet is allowed to buy up to `amount` pieces from allowlist-gated `Market`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AllowlistProof {
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
 }
 
 #[account]
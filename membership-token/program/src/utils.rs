@@ -1,10 +1,16 @@
 //! Module provide runtime utilities
 
-use crate::{id, ErrorCode};
+use crate::{
+    id, merkle_proof,
+    state::{AllowlistProof, Market, MarketState, SellingResource, TradeHistory},
+    ErrorCode, PurchaseCompleted,
+};
 use anchor_lang::{
     prelude::*,
     solana_program::{program::invoke_signed, system_instruction},
 };
+use anchor_spl::token::{self, TokenAccount};
+use std::convert::TryFrom;
 
 pub const NAME_MAX_LEN: usize = 40; // max len of a string buffer in bytes
 pub const NAME_DEFAULT_SIZE: usize = 4 + NAME_MAX_LEN; // max lenght of serialized string (str_len + <buffer>)
@@ -13,6 +19,11 @@ pub const DESCRIPTION_DEFAULT_SIZE: usize = 4 + DESCRIPTION_MAX_LEN;
 pub const HOLDER_PREFIX: &str = "holder";
 pub const HISTORY_PREFIX: &str = "history";
 pub const VAULT_OWNER_PREFIX: &str = "mt_vault";
+pub const EDITION_ACCOUNTS_LEN: usize = 4; // accounts required to mint one edition in `BuyBatch`
+pub const BUY_BATCH_MAX_SIZE: u64 = 4; // max editions per `BuyBatch` to fit into compute budget
+pub const MAX_PRICE_TIERS: usize = 4;
+pub const MAX_REFERRERS: usize = 4;
+pub const MAX_BASIS_POINTS: u16 = 10_000;
 
 /// Runtime derivation check
 pub fn assert_derivation(
@@ -28,15 +39,18 @@ pub fn assert_derivation(
 }
 
 /// Return `treasury_owner` Pubkey and bump seed.
+/// Seeds include `market`, see README for the migration notes.
 pub fn find_treasury_owner_address(
     treasury_mint: &Pubkey,
     selling_resource: &Pubkey,
+    market: &Pubkey,
 ) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
             HOLDER_PREFIX.as_bytes(),
             treasury_mint.as_ref(),
             selling_resource.as_ref(),
+            market.as_ref(),
         ],
         &id(),
     )
@@ -81,6 +95,45 @@ pub fn sys_create_account<'a>(
     Ok(())
 }
 
+/// Wrapper of `transfer` instruction from `system_program` program
+#[inline(always)]
+pub fn sys_transfer<'a>(
+    from: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    lamports: u64,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke_signed(
+        &system_instruction::transfer(from.key, to.key, lamports),
+        &[from.clone(), to.clone()],
+        signers_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// Transfer `amount` of lamports or tokens from the buyer to `destination`
+pub fn transfer_payment<'a>(
+    is_native: bool,
+    user_wallet: &AccountInfo<'a>,
+    user_token_account: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    if is_native {
+        sys_transfer(user_wallet, destination, amount, &[])
+    } else {
+        let cpi_accounts = token::Transfer {
+            from: user_token_account.clone(),
+            to: destination.clone(),
+            authority: user_wallet.clone(),
+        };
+        let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)
+    }
+}
+
 /// Wrapper of `mint_new_edition_from_master_edition_via_token` instruction from `mpl_token_metadata` program
 #[inline(always)]
 pub fn mpl_mint_new_edition_from_master_edition_via_token<'a>(
@@ -155,3 +208,207 @@ pub fn assert_keys_equal(key1: Pubkey, key2: Pubkey) -> ProgramResult {
         Ok(())
     }
 }
+
+/// Calculate referrer cut of `price` in basis points
+pub fn calculate_referral_fee(price: u64, basis_points: u16) -> Result<u64, ProgramError> {
+    let fee = (price as u128)
+        .checked_mul(basis_points as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(MAX_BASIS_POINTS as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(u64::try_from(fee).map_err(|_| ErrorCode::MathOverflow)?)
+}
+
+/// Account owner check
+pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> ProgramResult {
+    if account.owner != owner {
+        Err(ErrorCode::IncorrectOwner.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Check, that `account` is token `Account` of `mint` owned by `owner`
+pub fn assert_token_account(account: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> ProgramResult {
+    assert_owned_by(account, &spl_token::id())?;
+
+    let token_account = TokenAccount::try_deserialize(&mut account.data.borrow().as_ref())?;
+    assert_keys_equal(token_account.mint, *mint)?;
+    assert_keys_equal(token_account.owner, *owner)?;
+
+    Ok(())
+}
+
+/// Return amount of lamports or tokens, collected by `treasury_holder`
+pub fn get_treasury_amount(
+    treasury_holder: &AccountInfo,
+    is_native: bool,
+) -> Result<u64, ProgramError> {
+    if is_native {
+        // Native treasury keeps rent exempt minimum, which is not a part of collected funds
+        Ok(treasury_holder
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0)))
+    } else {
+        let token_account =
+            TokenAccount::try_deserialize(&mut treasury_holder.data.borrow().as_ref())?;
+        Ok(token_account.amount)
+    }
+}
+
+/// Check, that `wallet` is on the allowlist defined by `root` and not exceeds allowlisted amount
+pub fn assert_allowlisted(
+    root: [u8; 32],
+    wallet: &Pubkey,
+    allowlist_proof: &Option<AllowlistProof>,
+    already_bought: u64,
+) -> ProgramResult {
+    let allowlist_proof = allowlist_proof
+        .as_ref()
+        .ok_or(ErrorCode::AllowlistProofIsNotProvided)?;
+
+    let leaf = anchor_lang::solana_program::keccak::hashv(&[
+        &[0x00],
+        &wallet.to_bytes(),
+        &allowlist_proof.amount.to_le_bytes(),
+    ]);
+
+    if !merkle_proof::verify(&allowlist_proof.proof, root, leaf.0) {
+        return Err(ErrorCode::InvalidAllowlistProof.into());
+    }
+
+    if already_bought > allowlist_proof.amount {
+        return Err(ErrorCode::UserReachBuyLimit.into());
+    }
+
+    Ok(())
+}
+
+/// Validate purchase of `amount` editions by `user_wallet` and pay for them.
+/// Updates `trade_history` and returns `SellingResource` supply after the purchase,
+/// so editions should be minted by the caller starting from the current supply.
+pub fn process_purchase<'info>(
+    market: &Account<'info, Market>,
+    selling_resource: &SellingResource,
+    trade_history: &mut TradeHistory,
+    user_wallet: &AccountInfo<'info>,
+    user_token_account: &AccountInfo<'info>,
+    treasury_holder: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    referrer: Option<&AccountInfo<'info>>,
+    allowlist_proof: &Option<AllowlistProof>,
+    amount: u64,
+    timestamp: u64,
+) -> Result<u64, ProgramError> {
+    // Check, that `Market` is not suspended
+    if market.state == MarketState::Suspended {
+        return Err(ErrorCode::MarketIsSuspended.into());
+    }
+
+    // Check, that `Market` is started
+    if market.start_date > timestamp {
+        return Err(ErrorCode::MarketIsNotStarted.into());
+    }
+
+    // Check, that `Market` is ended or finalized
+    if market.is_ended(timestamp) {
+        return Err(ErrorCode::MarketIsEnded.into());
+    }
+
+    if trade_history.market != market.key() {
+        trade_history.market = market.key();
+    }
+
+    if trade_history.wallet != user_wallet.key() {
+        trade_history.wallet = user_wallet.key();
+    }
+
+    let already_bought = trade_history
+        .already_bought
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Check, that user not reach buy limit
+    if let Some(pieces_in_one_wallet) = market.pieces_in_one_wallet {
+        if already_bought > pieces_in_one_wallet {
+            return Err(ErrorCode::UserReachBuyLimit.into());
+        }
+    }
+
+    // Check, that user is on the allowlist
+    if let Some(allowlist_root) = market.allowlist_root {
+        assert_allowlisted(
+            allowlist_root,
+            &user_wallet.key(),
+            allowlist_proof,
+            already_bought,
+        )?;
+    }
+
+    let supply = selling_resource
+        .supply
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Check, that `SellingResource::max_supply` is not overflowed by `supply`
+    if let Some(max_supply) = selling_resource.max_supply {
+        if supply > max_supply {
+            return Err(ErrorCode::SupplyIsGtThanMaxSupply.into());
+        }
+    }
+
+    // Pay for all editions at once
+    let price = market
+        .price_at(timestamp)
+        .checked_mul(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Split payment between referrer and treasury
+    let referrer = referrer.filter(|_| market.referral_fee_basis_points > 0);
+    let referral_fee = if let Some(referrer) = referrer {
+        if !market.referrers.contains(referrer.key) {
+            return Err(ErrorCode::InvalidReferrer.into());
+        }
+
+        let referral_fee = calculate_referral_fee(price, market.referral_fee_basis_points)?;
+
+        transfer_payment(
+            market.is_native(),
+            user_wallet,
+            user_token_account,
+            referrer,
+            token_program,
+            referral_fee,
+        )?;
+
+        referral_fee
+    } else {
+        0
+    };
+
+    transfer_payment(
+        market.is_native(),
+        user_wallet,
+        user_token_account,
+        treasury_holder,
+        token_program,
+        price
+            .checked_sub(referral_fee)
+            .ok_or(ErrorCode::MathOverflow)?,
+    )?;
+
+    trade_history.already_bought = already_bought;
+
+    emit!(PurchaseCompleted {
+        market: market.key(),
+        wallet: user_wallet.key(),
+        amount,
+        price,
+        referrer: referrer.map(|referrer| referrer.key()),
+        referral_fee,
+        supply,
+    });
+
+    Ok(supply)
+}
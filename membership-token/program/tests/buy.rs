@@ -5,24 +5,40 @@ mod buy {
     use crate::{
         setup_context,
         utils::{
-            helpers::{airdrop, create_mint, create_token_account, mint_to, wait},
-            setup_functions::{setup_selling_resource, setup_store},
+            helpers::{
+                airdrop, allowlist_leaf, allowlist_tree, assert_error, create_mint,
+                create_token_account, get_account_data, get_events, mint_to, wait,
+            },
+            setup_functions::{
+                buy, buy_with_proof, change_referrers, fund_buyer, market_args, resume_market,
+                setup_buyer, setup_market_with_args, setup_selling_resource, setup_store,
+                suspend_market,
+            },
         },
     };
     use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
     use chrono::{Duration, Utc};
     use mpl_membership_token::{
-        accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
-        state::{SellingResource, TradeHistory},
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{AllowlistProof, Market, MarketState, SellingResource, TradeHistory},
         utils::{
             find_trade_history_address, find_treasury_owner_address, find_vault_owner_address,
         },
+        PurchaseCompleted,
     };
     use solana_program::clock::Clock;
     use solana_program_test::*;
     use solana_sdk::{
-        instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
-        system_program, sysvar, transaction::Transaction, transport::TransportError,
+        instruction::{AccountMeta, Instruction},
+        program_pack::Pack,
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program, sysvar,
+        transaction::Transaction,
+        transport::TransportError,
     };
 
     #[tokio::test]
@@ -31,7 +47,7 @@ mod buy {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         airdrop(
             &mut context,
@@ -54,6 +70,7 @@ mod buy {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -95,6 +112,10 @@ mod buy {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -253,6 +274,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -295,6 +317,1072 @@ mod buy {
 
         assert_eq!(selling_resource_data.supply, 1);
         assert_eq!(trade_history_data.already_bought, 1);
+
+        let event = get_events::<PurchaseCompleted>()
+            .into_iter()
+            .find(|event| event.market == market_keypair.pubkey())
+            .expect("PurchaseCompleted event is not emitted");
+
+        assert_eq!(event.wallet, context.payer.pubkey());
+        assert_eq!(event.amount, 1);
+        assert_eq!(event.price, price);
+        assert_eq!(event.referrer, None);
+        assert_eq!(event.referral_fee, 0);
+        assert_eq!(event.supply, 1);
+    }
+
+    #[tokio::test]
+    async fn success_with_referrer() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        airdrop(
+            &mut context,
+            &selling_resource_owner_keypair.pubkey(),
+            10_000_000_000,
+        )
+        .await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = Utc::now().timestamp() as u64;
+
+        let name = "Marktname".to_string();
+        let description = "Marktbeschreibung".to_string();
+        let mutable = true;
+        let price = 1_000_000;
+        let pieces_in_one_wallet = Some(1);
+
+        // CreateMarket
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasyry_owner_bump,
+            name: name.to_owned(),
+            description: description.to_owned(),
+            mutable,
+            price,
+            pieces_in_one_wallet,
+            start_date,
+            end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 1_000,
+            dutch_auction: None,
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        // Buy setup
+        let selling_resource_data = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let selling_resource =
+            SellingResource::try_deserialize(&mut selling_resource_data.as_ref()).unwrap();
+
+        let (trade_history, trade_history_bump) =
+            find_trade_history_address(&context.payer.pubkey(), &market_keypair.pubkey());
+        let (owner, vault_owner_bump) =
+            find_vault_owner_address(&selling_resource.resource, &selling_resource.store);
+
+        let payer_pubkey = context.payer.pubkey();
+
+        let user_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &user_token_account,
+            &treasury_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        mint_to(
+            &mut context,
+            &treasury_mint_keypair.pubkey(),
+            &user_token_account.pubkey(),
+            &admin_wallet,
+            1_000_000,
+        )
+        .await;
+
+        let referrer = Keypair::new();
+        create_token_account(
+            &mut context,
+            &referrer,
+            &treasury_mint_keypair.pubkey(),
+            &Keypair::new().pubkey(),
+        )
+        .await;
+
+        change_referrers(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            vec![referrer.pubkey()],
+        )
+        .await
+        .unwrap();
+
+        let new_mint_keypair = Keypair::new();
+        create_mint(&mut context, &new_mint_keypair, &payer_pubkey, 0).await;
+
+        let new_mint_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &new_mint_token_account,
+            &new_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        let payer_keypair = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        mint_to(
+            &mut context,
+            &new_mint_keypair.pubkey(),
+            &new_mint_token_account.pubkey(),
+            &payer_keypair,
+            1,
+        )
+        .await;
+
+        let (master_edition_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (master_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (edition_marker, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+                selling_resource.supply.to_string().as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        // Buy
+        let mut accounts = mpl_membership_token_accounts::Buy {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            user_token_account: user_token_account.pubkey(),
+            user_wallet: context.payer.pubkey(),
+            trade_history,
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            new_metadata,
+            new_edition,
+            master_edition,
+            new_mint: new_mint_keypair.pubkey(),
+            edition_marker,
+            vault: selling_resource.vault,
+            owner,
+            master_edition_metadata,
+            clock: sysvar::clock::id(),
+            rent: sysvar::rent::id(),
+            token_metadata_program: mpl_token_metadata::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+        accounts.push(AccountMeta::new(referrer.pubkey(), false));
+
+        let data = mpl_membership_token_instruction::Buy {
+            _trade_history_bump: trade_history_bump,
+            vault_owner_bump,
+            allowlist_proof: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        context.warp_to_slot(clock.slot + 3).unwrap();
+
+        // Checks
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        let trade_history_acc = context
+            .banks_client
+            .get_account(trade_history)
+            .await
+            .unwrap()
+            .unwrap();
+        let trade_history_data =
+            TradeHistory::try_deserialize(&mut trade_history_acc.data.as_ref()).unwrap();
+
+        let referrer_acc = context
+            .banks_client
+            .get_account(referrer.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let referrer_data = spl_token::state::Account::unpack(&referrer_acc.data).unwrap();
+
+        let treasury_holder_acc = context
+            .banks_client
+            .get_account(treasury_holder_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let treasury_holder_data =
+            spl_token::state::Account::unpack(&treasury_holder_acc.data).unwrap();
+
+        assert_eq!(selling_resource_data.supply, 1);
+        assert_eq!(trade_history_data.already_bought, 1);
+        assert_eq!(referrer_data.amount, 100_000);
+        assert_eq!(treasury_holder_data.amount, 900_000);
+    }
+
+    #[tokio::test]
+    async fn success_native() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        airdrop(
+            &mut context,
+            &selling_resource_owner_keypair.pubkey(),
+            10_000_000_000,
+        )
+        .await;
+
+        let market_keypair = Keypair::new();
+
+        // Native SOL is collected directly by `treasury_owner`
+        let treasury_mint = spl_token::native_mint::id();
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint,
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let start_date = Utc::now().timestamp() as u64;
+
+        let name = "Marktname".to_string();
+        let description = "Marktbeschreibung".to_string();
+        let mutable = true;
+        let price = 1_000_000;
+        let pieces_in_one_wallet = Some(1);
+
+        // CreateMarket
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint,
+            treasury_holder: treasury_owner,
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasyry_owner_bump,
+            name: name.to_owned(),
+            description: description.to_owned(),
+            mutable,
+            price,
+            pieces_in_one_wallet,
+            start_date,
+            end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        // Buy setup
+        let selling_resource_data = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let selling_resource =
+            SellingResource::try_deserialize(&mut selling_resource_data.as_ref()).unwrap();
+
+        let (trade_history, trade_history_bump) =
+            find_trade_history_address(&context.payer.pubkey(), &market_keypair.pubkey());
+        let (owner, vault_owner_bump) =
+            find_vault_owner_address(&selling_resource.resource, &selling_resource.store);
+
+        let payer_pubkey = context.payer.pubkey();
+
+        let new_mint_keypair = Keypair::new();
+        create_mint(&mut context, &new_mint_keypair, &payer_pubkey, 0).await;
+
+        let new_mint_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &new_mint_token_account,
+            &new_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        let payer_keypair = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        mint_to(
+            &mut context,
+            &new_mint_keypair.pubkey(),
+            &new_mint_token_account.pubkey(),
+            &payer_keypair,
+            1,
+        )
+        .await;
+
+        let (master_edition_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (master_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (edition_marker, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+                selling_resource.supply.to_string().as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        // Buy
+        let accounts = mpl_membership_token_accounts::Buy {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            user_token_account: payer_pubkey,
+            user_wallet: context.payer.pubkey(),
+            trade_history,
+            treasury_holder: treasury_owner,
+            new_metadata,
+            new_edition,
+            master_edition,
+            new_mint: new_mint_keypair.pubkey(),
+            edition_marker,
+            vault: selling_resource.vault,
+            owner,
+            master_edition_metadata,
+            clock: sysvar::clock::id(),
+            rent: sysvar::rent::id(),
+            token_metadata_program: mpl_token_metadata::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::Buy {
+            _trade_history_bump: trade_history_bump,
+            vault_owner_bump,
+            allowlist_proof: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        context.warp_to_slot(clock.slot + 3).unwrap();
+
+        // Checks
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        let trade_history_acc = context
+            .banks_client
+            .get_account(trade_history)
+            .await
+            .unwrap()
+            .unwrap();
+        let trade_history_data =
+            TradeHistory::try_deserialize(&mut trade_history_acc.data.as_ref()).unwrap();
+
+        let treasury_owner_acc = context
+            .banks_client
+            .get_account(treasury_owner)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Native treasury is kept rent exempt since `Market` creation
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        assert_eq!(selling_resource_data.supply, 1);
+        assert_eq!(trade_history_data.already_bought, 1);
+        assert_eq!(treasury_owner_acc.lamports, price + rent.minimum_balance(0));
+    }
+
+    #[tokio::test]
+    async fn success_allowlisted() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        airdrop(
+            &mut context,
+            &selling_resource_owner_keypair.pubkey(),
+            10_000_000_000,
+        )
+        .await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = Utc::now().timestamp() as u64;
+
+        let name = "Marktname".to_string();
+        let description = "Marktbeschreibung".to_string();
+        let mutable = true;
+        let price = 1_000_000;
+        let pieces_in_one_wallet = Some(1);
+
+        // Allowlist with a single leaf, so the root is the leaf itself
+        let allowlist_amount: u64 = 1;
+        let allowlist_root = solana_program::keccak::hashv(&[
+            &[0x00],
+            &context.payer.pubkey().to_bytes(),
+            &allowlist_amount.to_le_bytes(),
+        ])
+        .0;
+
+        // CreateMarket
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasyry_owner_bump,
+            name: name.to_owned(),
+            description: description.to_owned(),
+            mutable,
+            price,
+            pieces_in_one_wallet,
+            start_date,
+            end_date: None,
+            allowlist_root: Some(allowlist_root),
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        // Buy setup
+        let selling_resource_data = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let selling_resource =
+            SellingResource::try_deserialize(&mut selling_resource_data.as_ref()).unwrap();
+
+        let (trade_history, trade_history_bump) =
+            find_trade_history_address(&context.payer.pubkey(), &market_keypair.pubkey());
+        let (owner, vault_owner_bump) =
+            find_vault_owner_address(&selling_resource.resource, &selling_resource.store);
+
+        let payer_pubkey = context.payer.pubkey();
+
+        let user_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &user_token_account,
+            &treasury_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        mint_to(
+            &mut context,
+            &treasury_mint_keypair.pubkey(),
+            &user_token_account.pubkey(),
+            &admin_wallet,
+            1_000_000,
+        )
+        .await;
+
+        let new_mint_keypair = Keypair::new();
+        create_mint(&mut context, &new_mint_keypair, &payer_pubkey, 0).await;
+
+        let new_mint_token_account = Keypair::new();
+        create_token_account(
+            &mut context,
+            &new_mint_token_account,
+            &new_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        let payer_keypair = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+        mint_to(
+            &mut context,
+            &new_mint_keypair.pubkey(),
+            &new_mint_token_account.pubkey(),
+            &payer_keypair,
+            1,
+        )
+        .await;
+
+        let (master_edition_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (master_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (edition_marker, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+                selling_resource.supply.to_string().as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        // Buy
+        let accounts = mpl_membership_token_accounts::Buy {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            user_token_account: user_token_account.pubkey(),
+            user_wallet: context.payer.pubkey(),
+            trade_history,
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            new_metadata,
+            new_edition,
+            master_edition,
+            new_mint: new_mint_keypair.pubkey(),
+            edition_marker,
+            vault: selling_resource.vault,
+            owner,
+            master_edition_metadata,
+            clock: sysvar::clock::id(),
+            rent: sysvar::rent::id(),
+            token_metadata_program: mpl_token_metadata::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::Buy {
+            _trade_history_bump: trade_history_bump,
+            vault_owner_bump,
+            allowlist_proof: Some(AllowlistProof {
+                amount: allowlist_amount,
+                proof: Vec::new(),
+            }),
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        context.warp_to_slot(clock.slot + 3).unwrap();
+
+        // Checks
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        let trade_history_acc = context
+            .banks_client
+            .get_account(trade_history)
+            .await
+            .unwrap()
+            .unwrap();
+        let trade_history_data =
+            TradeHistory::try_deserialize(&mut trade_history_acc.data.as_ref()).unwrap();
+
+        assert_eq!(selling_resource_data.supply, 1);
+        assert_eq!(trade_history_data.already_bought, 1);
+    }
+
+    /// Setup `Market` gated by allowlist of `wallets`, each allowed to buy `allowlist_amount`
+    /// pieces, and fund them. Returns `Market` and buyers' treasury token accounts
+    async fn setup_allowlisted_market(
+        context: &mut ProgramTestContext,
+        wallets: &[&Keypair],
+        allowlist_amount: u64,
+    ) -> (Keypair, Vec<Keypair>, Vec<Vec<[u8; 32]>>) {
+        let (admin_wallet, store_keypair) = setup_store(context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(context, &admin_wallet, &store_keypair, Some(10)).await;
+
+        let leaves = wallets
+            .iter()
+            .map(|wallet| allowlist_leaf(&wallet.pubkey(), allowlist_amount))
+            .collect::<Vec<_>>();
+        let (allowlist_root, proofs) = allowlist_tree(&leaves);
+
+        let mut args = market_args(Utc::now().timestamp() as u64);
+        args.pieces_in_one_wallet = None;
+        args.allowlist_root = Some(allowlist_root);
+
+        let market_keypair = setup_market_with_args(
+            context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        let mut user_token_accounts = Vec::new();
+        for wallet in wallets {
+            user_token_accounts
+                .push(fund_buyer(context, &admin_wallet, &market_keypair.pubkey(), wallet).await);
+        }
+
+        wait(context, Duration::seconds(2)).await;
+
+        (market_keypair, user_token_accounts, proofs)
+    }
+
+    #[tokio::test]
+    async fn fail_allowlist_proof_is_not_provided() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let user_wallet = Keypair::new();
+        let (market_keypair, user_token_accounts, _) =
+            setup_allowlisted_market(&mut context, &[&user_wallet], 1).await;
+
+        let err = buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_accounts[0].pubkey(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::AllowlistProofIsNotProvided);
+    }
+
+    #[tokio::test]
+    async fn success_allowlisted_multi_level() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let wallets = (0..4).map(|_| Keypair::new()).collect::<Vec<_>>();
+        let (market_keypair, user_token_accounts, proofs) =
+            setup_allowlisted_market(&mut context, &wallets.iter().collect::<Vec<_>>(), 1).await;
+
+        assert_eq!(proofs[2].len(), 2);
+
+        buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[2],
+            &user_token_accounts[2].pubkey(),
+            None,
+            Some(AllowlistProof {
+                amount: 1,
+                proof: proofs[2].clone(),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let (trade_history, _) =
+            find_trade_history_address(&wallets[2].pubkey(), &market_keypair.pubkey());
+        let trade_history = get_account_data::<TradeHistory>(&mut context, &trade_history).await;
+
+        assert_eq!(trade_history.already_bought, 1);
+    }
+
+    #[tokio::test]
+    async fn fail_invalid_allowlist_proof() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let wallets = (0..4).map(|_| Keypair::new()).collect::<Vec<_>>();
+        let (market_keypair, user_token_accounts, proofs) =
+            setup_allowlisted_market(&mut context, &wallets.iter().collect::<Vec<_>>(), 1).await;
+
+        // Proof of another wallet
+        let err = buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[1],
+            &user_token_accounts[1].pubkey(),
+            None,
+            Some(AllowlistProof {
+                amount: 1,
+                proof: proofs[0].clone(),
+            }),
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::InvalidAllowlistProof);
+
+        // Another proof for the wallet
+        let err = buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[0],
+            &user_token_accounts[0].pubkey(),
+            None,
+            Some(AllowlistProof {
+                amount: 1,
+                proof: proofs[1].clone(),
+            }),
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::InvalidAllowlistProof);
+
+        // Allowlisted amount is a part of the leaf
+        let err = buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[0],
+            &user_token_accounts[0].pubkey(),
+            None,
+            Some(AllowlistProof {
+                amount: 2,
+                proof: proofs[0].clone(),
+            }),
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::InvalidAllowlistProof);
+    }
+
+    #[tokio::test]
+    async fn fail_allowlisted_amount_is_exceeded() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let wallets = (0..2).map(|_| Keypair::new()).collect::<Vec<_>>();
+        let (market_keypair, user_token_accounts, proofs) =
+            setup_allowlisted_market(&mut context, &wallets.iter().collect::<Vec<_>>(), 1).await;
+
+        let allowlist_proof = AllowlistProof {
+            amount: 1,
+            proof: proofs[0].clone(),
+        };
+
+        buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[0],
+            &user_token_accounts[0].pubkey(),
+            None,
+            Some(allowlist_proof.clone()),
+        )
+        .await
+        .unwrap();
+
+        let err = buy_with_proof(
+            &mut context,
+            &market_keypair.pubkey(),
+            &wallets[0],
+            &user_token_accounts[0].pubkey(),
+            None,
+            Some(allowlist_proof),
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::UserReachBuyLimit);
     }
 
     #[tokio::test]
@@ -303,7 +1391,7 @@ mod buy {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         airdrop(
             &mut context,
@@ -326,6 +1414,7 @@ mod buy {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -370,6 +1459,10 @@ mod buy {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -526,6 +1619,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -561,7 +1655,7 @@ mod buy {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         airdrop(
             &mut context,
@@ -584,6 +1678,7 @@ mod buy {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -629,6 +1724,10 @@ mod buy {
             pieces_in_one_wallet,
             start_date,
             end_date: Some(end_date),
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -787,6 +1886,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -822,7 +1922,7 @@ mod buy {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         airdrop(
             &mut context,
@@ -845,6 +1945,7 @@ mod buy {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -886,6 +1987,10 @@ mod buy {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -1044,6 +2149,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -1091,7 +2197,7 @@ mod buy {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, vault) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         airdrop(
             &mut context,
@@ -1114,6 +2220,7 @@ mod buy {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -1155,6 +2262,10 @@ mod buy {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -1313,6 +2424,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -1426,6 +2538,7 @@ mod buy {
         let data = mpl_membership_token_instruction::Buy {
             _trade_history_bump: trade_history_bump,
             vault_owner_bump,
+            allowlist_proof: None,
         }
         .data();
 
@@ -1454,4 +2567,162 @@ mod buy {
             _ => assert!(false),
         }
     }
+
+    #[tokio::test]
+    async fn fail_market_is_suspended() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            market_args(Utc::now().timestamp() as u64),
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        suspend_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        let err = buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsSuspended);
+    }
+
+    #[tokio::test]
+    async fn success_after_resume() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            market_args(Utc::now().timestamp() as u64),
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        suspend_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        resume_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let market = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+        assert_eq!(market.state, MarketState::Active);
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let selling_resource =
+            get_account_data::<SellingResource>(&mut context, &selling_resource_keypair.pubkey())
+                .await;
+        assert_eq!(selling_resource.supply, 1);
+    }
+
+    #[tokio::test]
+    async fn fail_referrer_is_not_approved() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let mut args = market_args(Utc::now().timestamp() as u64);
+        args.referral_fee_basis_points = 1_000;
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        let approved_referrer = Keypair::new();
+        change_referrers(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            vec![approved_referrer.pubkey()],
+        )
+        .await
+        .unwrap();
+
+        // Buyer's own accounts are not approved as well as any other account
+        for referrer in &[
+            user_wallet.pubkey(),
+            user_token_account.pubkey(),
+            Keypair::new().pubkey(),
+        ] {
+            let err = buy(
+                &mut context,
+                &market_keypair.pubkey(),
+                &user_wallet,
+                &user_token_account.pubkey(),
+                Some(referrer),
+            )
+            .await
+            .unwrap_err();
+
+            assert_error(err, ErrorCode::InvalidReferrer);
+        }
+    }
 }
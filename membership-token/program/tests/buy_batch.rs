@@ -0,0 +1,524 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod buy_batch {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{airdrop, assert_error, create_mint, create_token_account, mint_to, wait},
+            setup_functions::{resume_market, setup_selling_resource, setup_store, suspend_market},
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{SellingResource, TradeHistory},
+        utils::{
+            find_trade_history_address, find_treasury_owner_address, find_vault_owner_address,
+            BUY_BATCH_MAX_SIZE,
+        },
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::Keypair,
+        signer::Signer,
+        system_program, sysvar,
+        transaction::Transaction,
+        transport::TransportError,
+    };
+
+    /// Create new mint for edition and return `[new_metadata, new_edition, new_mint, edition_marker]` metas
+    async fn setup_edition_accounts(
+        context: &mut ProgramTestContext,
+        resource_mint: &Pubkey,
+        edition: u64,
+    ) -> Vec<AccountMeta> {
+        let payer_pubkey = context.payer.pubkey();
+        let payer_keypair = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+        let new_mint_keypair = Keypair::new();
+        create_mint(context, &new_mint_keypair, &payer_pubkey, 0).await;
+
+        let new_mint_token_account = Keypair::new();
+        create_token_account(
+            context,
+            &new_mint_token_account,
+            &new_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        mint_to(
+            context,
+            &new_mint_keypair.pubkey(),
+            &new_mint_token_account.pubkey(),
+            &payer_keypair,
+            1,
+        )
+        .await;
+
+        let (new_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (new_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                new_mint_keypair.pubkey().as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (edition_marker, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                resource_mint.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+                (edition / mpl_token_metadata::state::EDITION_MARKER_BIT_SIZE)
+                    .to_string()
+                    .as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        vec![
+            AccountMeta::new(new_metadata, false),
+            AccountMeta::new(new_edition, false),
+            AccountMeta::new(new_mint_keypair.pubkey(), false),
+            AccountMeta::new(edition_marker, false),
+        ]
+    }
+
+    /// Setup `Market` without wallet limit and return `BuyBatch` instruction for `amount` editions,
+    /// `SellingResource`, `TradeHistory`, `Market` and `Market` owner
+    async fn setup_buy_batch(
+        context: &mut ProgramTestContext,
+        max_supply: Option<u64>,
+        amount: u64,
+    ) -> (Instruction, Pubkey, Pubkey, Pubkey, Keypair) {
+        let (admin_wallet, store_keypair) = setup_store(context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _vault) =
+            setup_selling_resource(context, &admin_wallet, &store_keypair, max_supply).await;
+
+        airdrop(
+            context,
+            &selling_resource_owner_keypair.pubkey(),
+            10_000_000_000,
+        )
+        .await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(context, &treasury_mint_keypair, &admin_wallet.pubkey(), 0).await;
+
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = Utc::now().timestamp() as u64;
+        let price = 1_000_000;
+
+        // CreateMarket
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasyry_owner_bump,
+            name: "Marktname".to_string(),
+            description: "Marktbeschreibung".to_string(),
+            mutable: true,
+            price,
+            pieces_in_one_wallet: None,
+            start_date,
+            end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(context, Duration::seconds(2)).await;
+
+        // BuyBatch setup
+        let selling_resource_data = context
+            .banks_client
+            .get_account(selling_resource_keypair.pubkey())
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        let selling_resource =
+            SellingResource::try_deserialize(&mut selling_resource_data.as_ref()).unwrap();
+
+        let (trade_history, trade_history_bump) =
+            find_trade_history_address(&context.payer.pubkey(), &market_keypair.pubkey());
+        let (owner, vault_owner_bump) =
+            find_vault_owner_address(&selling_resource.resource, &selling_resource.store);
+
+        let payer_pubkey = context.payer.pubkey();
+
+        let user_token_account = Keypair::new();
+        create_token_account(
+            context,
+            &user_token_account,
+            &treasury_mint_keypair.pubkey(),
+            &payer_pubkey,
+        )
+        .await;
+
+        mint_to(
+            context,
+            &treasury_mint_keypair.pubkey(),
+            &user_token_account.pubkey(),
+            &admin_wallet,
+            price * amount,
+        )
+        .await;
+
+        let (master_edition_metadata, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let (master_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let mut accounts = mpl_membership_token_accounts::BuyBatch {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            user_token_account: user_token_account.pubkey(),
+            user_wallet: payer_pubkey,
+            trade_history,
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            master_edition,
+            vault: selling_resource.vault,
+            owner,
+            master_edition_metadata,
+            clock: sysvar::clock::id(),
+            rent: sysvar::rent::id(),
+            token_metadata_program: mpl_token_metadata::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        for i in 0..amount {
+            accounts.extend(
+                setup_edition_accounts(
+                    context,
+                    &selling_resource.resource,
+                    selling_resource.supply + i,
+                )
+                .await,
+            );
+        }
+
+        let data = mpl_membership_token_instruction::BuyBatch {
+            _trade_history_bump: trade_history_bump,
+            vault_owner_bump,
+            allowlist_proof: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        (
+            instruction,
+            selling_resource_keypair.pubkey(),
+            trade_history,
+            market_keypair.pubkey(),
+            selling_resource_owner_keypair,
+        )
+    }
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (instruction, selling_resource, trade_history, _, _) =
+            setup_buy_batch(&mut context, Some(2), 2).await;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Checks
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource)
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        let trade_history_acc = context
+            .banks_client
+            .get_account(trade_history)
+            .await
+            .unwrap()
+            .unwrap();
+        let trade_history_data =
+            TradeHistory::try_deserialize(&mut trade_history_acc.data.as_ref()).unwrap();
+
+        assert_eq!(selling_resource_data.supply, 2);
+        assert_eq!(trade_history_data.already_bought, 2);
+    }
+
+    #[tokio::test]
+    async fn fail_invalid_remaining_accounts() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (mut instruction, _, _, _, _) = setup_buy_batch(&mut context, Some(2), 1).await;
+        instruction.accounts.pop();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_supply_is_gt_than_max_supply() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (instruction, _, _, _, _) = setup_buy_batch(&mut context, Some(1), 2).await;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn success_max_batch_size() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (instruction, selling_resource, trade_history, _, _) =
+            setup_buy_batch(&mut context, Some(BUY_BATCH_MAX_SIZE), BUY_BATCH_MAX_SIZE).await;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Checks
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource)
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        let trade_history_acc = context
+            .banks_client
+            .get_account(trade_history)
+            .await
+            .unwrap()
+            .unwrap();
+        let trade_history_data =
+            TradeHistory::try_deserialize(&mut trade_history_acc.data.as_ref()).unwrap();
+
+        assert_eq!(selling_resource_data.supply, BUY_BATCH_MAX_SIZE);
+        assert_eq!(trade_history_data.already_bought, BUY_BATCH_MAX_SIZE);
+    }
+
+    #[tokio::test]
+    async fn fail_batch_is_too_big() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        // Supply is enough, but batch exceeds the limit
+        let (instruction, _, _, _, _) = setup_buy_batch(
+            &mut context,
+            Some(BUY_BATCH_MAX_SIZE + 1),
+            BUY_BATCH_MAX_SIZE + 1,
+        )
+        .await;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::BatchIsTooBig);
+    }
+
+    #[tokio::test]
+    async fn fail_market_is_suspended() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (instruction, _, _, market, market_owner) =
+            setup_buy_batch(&mut context, Some(2), 2).await;
+
+        suspend_market(&mut context, &market, &market_owner)
+            .await
+            .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsSuspended);
+    }
+
+    #[tokio::test]
+    async fn success_after_resume() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+
+        let (instruction, selling_resource, _, market, market_owner) =
+            setup_buy_batch(&mut context, Some(2), 2).await;
+
+        suspend_market(&mut context, &market, &market_owner)
+            .await
+            .unwrap();
+        resume_market(&mut context, &market, &market_owner)
+            .await
+            .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let selling_resource_acc = context
+            .banks_client
+            .get_account(selling_resource)
+            .await
+            .unwrap()
+            .unwrap();
+        let selling_resource_data =
+            SellingResource::try_deserialize(&mut selling_resource_acc.data.as_ref()).unwrap();
+
+        assert_eq!(selling_resource_data.supply, 2);
+    }
+}
@@ -0,0 +1,205 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod change_market_owner {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::get_events,
+            setup_functions::{setup_market, setup_selling_resource, setup_store},
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
+        state::Market, MarketOwnerChanged,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::{Instruction, InstructionError},
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+        transport::TransportError,
+    };
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let new_owner = Keypair::new();
+
+        let accounts = mpl_membership_token_accounts::ChangeMarketOwner {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            new_owner: new_owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangeMarketOwner {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair, &new_owner],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        assert_eq!(new_owner.pubkey(), market_data.owner);
+
+        let event = get_events::<MarketOwnerChanged>()
+            .into_iter()
+            .find(|event| event.market == market_keypair.pubkey())
+            .expect("MarketOwnerChanged event is not emitted");
+
+        assert_eq!(selling_resource_owner_keypair.pubkey(), event.owner);
+        assert_eq!(new_owner.pubkey(), event.new_owner);
+    }
+
+    #[tokio::test]
+    async fn failure_signer_is_not_owner() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let fake_owner = Keypair::new();
+
+        let accounts = mpl_membership_token_accounts::ChangeMarketOwner {
+            market: market_keypair.pubkey(),
+            owner: fake_owner.pubkey(),
+            new_owner: fake_owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangeMarketOwner {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &fake_owner],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_new_owner_is_not_signer() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let new_owner = Keypair::new();
+
+        let mut accounts = mpl_membership_token_accounts::ChangeMarketOwner {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            new_owner: new_owner.pubkey(),
+        }
+        .to_account_metas(None);
+
+        // Transfer to the key, which is not able to sign
+        accounts
+            .iter_mut()
+            .find(|meta| meta.pubkey == new_owner.pubkey())
+            .unwrap()
+            .is_signer = false;
+
+        let data = mpl_membership_token_instruction::ChangeMarketOwner {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(code),
+            )) => assert_eq!(code, 3010), // Anchor `AccountNotSigner`
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
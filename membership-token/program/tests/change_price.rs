@@ -0,0 +1,250 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod change_price {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{get_token_balance, wait},
+            setup_functions::{
+                buy, market_args, setup_buyer, setup_market, setup_market_with_args,
+                setup_selling_resource, setup_store,
+            },
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
+        state::{Market, PriceTier},
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::Instruction, signer::Signer, sysvar, transaction::Transaction,
+        transport::TransportError,
+    };
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::ChangePrice {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangePrice {
+            price: 2_000_000,
+            price_tiers: vec![PriceTier {
+                start_date: 1,
+                price: 3_000_000,
+            }],
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        assert_eq!(2_000_000, market_data.price);
+        assert_eq!(
+            vec![PriceTier {
+                start_date: 1,
+                price: 3_000_000,
+            }],
+            market_data.price_tiers
+        );
+        assert_eq!(3_000_000, market_data.price_at(1));
+        assert_eq!(2_000_000, market_data.price_at(0));
+    }
+
+    #[tokio::test]
+    async fn failure_price_tiers_are_not_sorted() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::ChangePrice {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangePrice {
+            price: 2_000_000,
+            price_tiers: vec![
+                PriceTier {
+                    start_date: 2,
+                    price: 3_000_000,
+                },
+                PriceTier {
+                    start_date: 1,
+                    price: 4_000_000,
+                },
+            ],
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn success_buy_at_tier_price() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            market_args(Utc::now().timestamp() as u64),
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::ChangePrice {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        // First tier is already active, second one never starts
+        let data = mpl_membership_token_instruction::ChangePrice {
+            price: 2_000_000,
+            price_tiers: vec![
+                PriceTier {
+                    start_date: 1,
+                    price: 3_000_000,
+                },
+                PriceTier {
+                    start_date: u64::MAX,
+                    price: 4_000_000,
+                },
+            ],
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        assert_eq!(
+            3_000_000,
+            get_token_balance(&mut context, &market_data.treasury_holder).await
+        );
+        assert_eq!(
+            7_000_000,
+            get_token_balance(&mut context, &user_token_account.pubkey()).await
+        );
+    }
+}
@@ -0,0 +1,130 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod change_referrers {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{assert_error, get_account_data, get_events},
+            setup_functions::{
+                change_referrers, setup_market, setup_selling_resource, setup_store,
+            },
+        },
+    };
+    use mpl_membership_token::{
+        error::ErrorCode, state::Market, utils::MAX_REFERRERS, ReferrersChanged,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::InstructionError, signature::Keypair, signer::Signer,
+        transaction::TransactionError, transport::TransportError,
+    };
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let referrers = vec![Keypair::new().pubkey(), Keypair::new().pubkey()];
+
+        change_referrers(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            referrers.clone(),
+        )
+        .await
+        .unwrap();
+
+        let market = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+        assert_eq!(market.referrers, referrers);
+
+        let event = get_events::<ReferrersChanged>()
+            .into_iter()
+            .find(|event| event.market == market_keypair.pubkey())
+            .expect("ReferrersChanged event is not emitted");
+        assert_eq!(event.referrers, referrers);
+    }
+
+    #[tokio::test]
+    async fn failure_referrers_are_too_many() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let referrers = (0..=MAX_REFERRERS)
+            .map(|_| Keypair::new().pubkey())
+            .collect();
+
+        let err = change_referrers(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            referrers,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::ReferrersAreTooMany);
+    }
+
+    #[tokio::test]
+    async fn failure_signer_is_not_owner() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let fake_owner = Keypair::new();
+
+        let err = change_referrers(
+            &mut context,
+            &market_keypair.pubkey(),
+            &fake_owner,
+            vec![fake_owner.pubkey()],
+        )
+        .await
+        .unwrap_err();
+
+        match err {
+            TransportError::TransactionError(TransactionError::InstructionError(
+                _,
+                InstructionError::Custom(code),
+            )) => assert_eq!(code, 2001), // Anchor `ConstraintHasOne`
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod change_store_admin {
+    use crate::{
+        setup_context,
+        utils::{helpers::get_events, setup_functions::setup_store},
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
+        state::Store, StoreAdminChanged,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::{Instruction, InstructionError},
+        signature::Keypair,
+        signer::Signer,
+        transaction::{Transaction, TransactionError},
+        transport::TransportError,
+    };
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let new_admin = Keypair::new();
+
+        let accounts = mpl_membership_token_accounts::ChangeStoreAdmin {
+            store: store_keypair.pubkey(),
+            admin: admin_wallet.pubkey(),
+            new_admin: new_admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangeStoreAdmin {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &admin_wallet, &new_admin],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let store_acc = context
+            .banks_client
+            .get_account(store_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let store_data = Store::try_deserialize(&mut store_acc.data.as_ref()).unwrap();
+
+        assert_eq!(new_admin.pubkey(), store_data.admin);
+
+        let event = get_events::<StoreAdminChanged>()
+            .into_iter()
+            .find(|event| event.store == store_keypair.pubkey())
+            .expect("StoreAdminChanged event is not emitted");
+
+        assert_eq!(admin_wallet.pubkey(), event.admin);
+        assert_eq!(new_admin.pubkey(), event.new_admin);
+    }
+
+    #[tokio::test]
+    async fn failure_signer_is_not_admin() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (_admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let fake_admin = Keypair::new();
+
+        let accounts = mpl_membership_token_accounts::ChangeStoreAdmin {
+            store: store_keypair.pubkey(),
+            admin: fake_admin.pubkey(),
+            new_admin: fake_admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ChangeStoreAdmin {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &fake_admin],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_new_admin_is_not_signer() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let new_admin = Keypair::new();
+
+        let mut accounts = mpl_membership_token_accounts::ChangeStoreAdmin {
+            store: store_keypair.pubkey(),
+            admin: admin_wallet.pubkey(),
+            new_admin: new_admin.pubkey(),
+        }
+        .to_account_metas(None);
+
+        // Transfer to the key, which is not able to sign
+        accounts
+            .iter_mut()
+            .find(|meta| meta.pubkey == new_admin.pubkey())
+            .unwrap()
+            .is_signer = false;
+
+        let data = mpl_membership_token_instruction::ChangeStoreAdmin {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &admin_wallet],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::TransactionError(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(code),
+            )) => assert_eq!(code, 3010), // Anchor `AccountNotSigner`
+            _ => panic!("Unexpected error: {:?}", err),
+        }
+    }
+}
@@ -0,0 +1,349 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod close_market {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{assert_error, get_events, mint_to},
+            setup_functions::{
+                close_market, finalize_market, market_args, setup_market,
+                setup_native_market_with_args, setup_selling_resource, setup_store,
+            },
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::Utc;
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts, error::ErrorCode,
+        instruction as mpl_membership_token_instruction, state::Market,
+        utils::find_treasury_owner_address, MarketClosed, MarketEnded,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{instruction::Instruction, signer::Signer, sysvar, transaction::Transaction};
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        finalize_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &market.treasury_mint,
+            &market.selling_resource,
+            &market_keypair.pubkey(),
+        );
+
+        let accounts = mpl_membership_token_accounts::CloseMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            treasury_holder: market.treasury_holder,
+            treasury_owner,
+            clock: sysvar::clock::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CloseMarket {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .unwrap();
+        let treasury_holder_acc = context
+            .banks_client
+            .get_account(market.treasury_holder)
+            .await
+            .unwrap();
+
+        assert!(market_acc.is_none());
+        assert!(treasury_holder_acc.is_none());
+
+        // `MarketEnded` is emitted only once by `FinalizeMarket`
+        let ended_events = get_events::<MarketEnded>()
+            .into_iter()
+            .filter(|event| event.market == market_keypair.pubkey())
+            .count();
+        assert_eq!(ended_events, 1);
+
+        let event = get_events::<MarketClosed>()
+            .into_iter()
+            .find(|event| event.market == market_keypair.pubkey())
+            .expect("MarketClosed event is not emitted");
+        assert_eq!(event.owner, selling_resource_owner_keypair.pubkey());
+    }
+
+    #[tokio::test]
+    async fn success_native() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_native_market_with_args(
+            &mut context,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            market_args(Utc::now().timestamp() as u64 + 5),
+        )
+        .await;
+
+        finalize_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        let treasury_owner_lamports = context
+            .banks_client
+            .get_balance(market.treasury_owner)
+            .await
+            .unwrap();
+        let owner_lamports = context
+            .banks_client
+            .get_balance(selling_resource_owner_keypair.pubkey())
+            .await
+            .unwrap();
+
+        close_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let treasury_owner_acc = context
+            .banks_client
+            .get_account(market.treasury_owner)
+            .await
+            .unwrap();
+
+        // Treasury rent and `Market` rent are returned to the owner
+        assert!(treasury_owner_acc.is_none());
+        assert_eq!(
+            owner_lamports + treasury_owner_lamports + market_acc.lamports,
+            context
+                .banks_client
+                .get_balance(selling_resource_owner_keypair.pubkey())
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn failure_treasury_is_not_empty() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        finalize_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        // Emulate collected funds
+        mint_to(
+            &mut context,
+            &market.treasury_mint,
+            &market.treasury_holder,
+            &admin_wallet,
+            1_000_000,
+        )
+        .await;
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &market.treasury_mint,
+            &market.selling_resource,
+            &market_keypair.pubkey(),
+        );
+
+        let accounts = mpl_membership_token_accounts::CloseMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            treasury_holder: market.treasury_holder,
+            treasury_owner,
+            clock: sysvar::clock::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CloseMarket {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::TreasuryIsNotEmpty);
+    }
+
+    #[tokio::test]
+    async fn failure_market_is_not_ended() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        // `Market` without `end_date` is not finalized
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &market.treasury_mint,
+            &market.selling_resource,
+            &market_keypair.pubkey(),
+        );
+
+        let accounts = mpl_membership_token_accounts::CloseMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            treasury_holder: market.treasury_holder,
+            treasury_owner,
+            clock: sysvar::clock::id(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CloseMarket {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsNotEnded);
+    }
+}
@@ -5,18 +5,29 @@ mod create_market {
     use std::time::SystemTime;
 
     use crate::utils::{
-        helpers::{create_mint, create_token_account},
-        setup_functions::{setup_market, setup_selling_resource, setup_store},
+        helpers::{
+            assert_error, create_mint, create_token_account, get_account_data, get_events,
+            get_token_balance, wait,
+        },
+        setup_functions::{
+            buy, market_args, setup_buyer, setup_market, setup_market_with_args,
+            setup_selling_resource, setup_store,
+        },
     };
     use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use solana_program::clock::Clock;
     use solana_program_test::*;
 
     use mpl_membership_token::{
-        accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
-        state::{Market, MarketState},
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{DutchAuction, Market, MarketState, Vesting},
         utils::{
             find_treasury_owner_address, puffed_out_string, DESCRIPTION_MAX_LEN, NAME_MAX_LEN,
         },
+        MarketCreated,
     };
     use solana_sdk::{
         instruction::Instruction, signature::Keypair, signer::Signer, system_program,
@@ -31,7 +42,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -47,6 +58,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -91,6 +103,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -143,6 +159,279 @@ mod create_market {
         assert_eq!(price, market_data.price);
         assert_eq!(pieces_in_one_wallet, market_data.pieces_in_one_wallet);
         assert_eq!(MarketState::Created, market_data.state);
+
+        let event = get_events::<MarketCreated>()
+            .into_iter()
+            .find(|event| event.market == market_keypair.pubkey())
+            .expect("MarketCreated event is not emitted");
+
+        assert_eq!(store_keypair.pubkey(), event.store);
+        assert_eq!(selling_resource_keypair.pubkey(), event.selling_resource);
+        assert_eq!(selling_resource_owner_keypair.pubkey(), event.owner);
+        assert_eq!(treasury_mint_keypair.pubkey(), event.treasury_mint);
+        assert_eq!(price, event.price);
+        assert_eq!(market_data.start_date, event.start_date);
+        assert_eq!(market_data.end_date, event.end_date);
+    }
+
+    #[tokio::test]
+    async fn success_dutch_auction() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = std::time::SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 5;
+
+        let name = "Marktname".to_string();
+        let description = "Marktbeschreibung".to_string();
+        let mutable = true;
+        let price = 1_000_000;
+        let pieces_in_one_wallet = Some(1);
+        let dutch_auction = DutchAuction {
+            start_price: price,
+            floor_price: price / 4,
+            decay_duration: 100,
+            step_interval: Some(10),
+        };
+
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasyry_owner_bump,
+            name: name.to_owned(),
+            description: description.to_owned(),
+            mutable,
+            price,
+            pieces_in_one_wallet,
+            start_date,
+            end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: Some(dutch_auction.clone()),
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        assert_eq!(Some(dutch_auction), market_data.dutch_auction);
+        assert_eq!(price, market_data.price_at(start_date));
+        assert_eq!(price, market_data.price_at(start_date + 9));
+        assert_eq!(925_000, market_data.price_at(start_date + 15));
+        assert_eq!(price / 4, market_data.price_at(start_date + 100));
+        assert_eq!(price / 4, market_data.price_at(start_date + 1_000));
+    }
+
+    #[tokio::test]
+    async fn success_buy_at_dutch_auction_price() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        // Price is still decaying at the moment of `Buy`
+        let mut args = market_args(Utc::now().timestamp() as u64);
+        args.dutch_auction = Some(DutchAuction {
+            start_price: 1_000_000,
+            floor_price: 250_000,
+            decay_duration: 1_000_000,
+            step_interval: None,
+        });
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+        let market_data = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+        let paid = get_token_balance(&mut context, &market_data.treasury_holder).await;
+
+        assert_eq!(market_data.price_at(clock.unix_timestamp as u64), paid);
+        assert!(paid < 1_000_000);
+        assert!(paid > 250_000);
+        assert_eq!(
+            10_000_000 - paid,
+            get_token_balance(&mut context, &user_token_account.pubkey()).await
+        );
+    }
+
+    #[tokio::test]
+    async fn success_native_markets_have_separate_treasuries() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let rent = context.banks_client.get_rent().await.unwrap();
+        let mut treasury_owners = Vec::new();
+
+        for _ in 0..2 {
+            let market_keypair = Keypair::new();
+
+            let treasury_mint = spl_token::native_mint::id();
+            let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+                &treasury_mint,
+                &selling_resource_keypair.pubkey(),
+                &market_keypair.pubkey(),
+            );
+
+            let start_date = std::time::SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                + 5;
+
+            let accounts = mpl_membership_token_accounts::CreateMarket {
+                market: market_keypair.pubkey(),
+                store: store_keypair.pubkey(),
+                selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+                selling_resource: selling_resource_keypair.pubkey(),
+                mint: treasury_mint,
+                treasury_holder: treasury_owner,
+                owner: treasury_owner,
+                system_program: system_program::id(),
+            }
+            .to_account_metas(None);
+
+            let data = mpl_membership_token_instruction::CreateMarket {
+                _treasyry_owner_bump: treasyry_owner_bump,
+                name: "Marktname".to_string(),
+                description: "Marktbeschreibung".to_string(),
+                mutable: true,
+                price: 1_000_000,
+                pieces_in_one_wallet: Some(1),
+                start_date,
+                end_date: None,
+                allowlist_root: None,
+                referral_fee_basis_points: 0,
+                dutch_auction: None,
+                vesting: None,
+            }
+            .data();
+
+            let instruction = Instruction {
+                program_id: mpl_membership_token::id(),
+                data,
+                accounts,
+            };
+
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&context.payer.pubkey()),
+                &[
+                    &context.payer,
+                    &market_keypair,
+                    &selling_resource_owner_keypair,
+                ],
+                context.last_blockhash,
+            );
+
+            context.banks_client.process_transaction(tx).await.unwrap();
+
+            let treasury_owner_acc = context
+                .banks_client
+                .get_account(treasury_owner)
+                .await
+                .expect("account not found")
+                .expect("account empty");
+
+            assert_eq!(treasury_owner_acc.lamports, rent.minimum_balance(0));
+
+            treasury_owners.push(treasury_owner);
+        }
+
+        assert_ne!(treasury_owners[0], treasury_owners[1]);
     }
 
     #[tokio::test]
@@ -151,7 +440,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = setup_market(
             &mut context,
@@ -186,7 +475,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -202,6 +491,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -247,6 +537,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -282,7 +576,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -298,6 +592,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -345,6 +640,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -381,7 +680,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -397,6 +696,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -442,6 +742,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -467,7 +771,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -483,6 +787,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -528,6 +833,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -564,7 +873,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -573,6 +882,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -618,6 +928,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -647,7 +961,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -663,6 +977,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -701,6 +1016,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -737,7 +1056,7 @@ mod create_market {
         let (admin_wallet, store_keypair) = setup_store(&mut context).await;
 
         let (selling_resource_keypair, selling_resource_owner_keypair, _) =
-            setup_selling_resource(&mut context, &admin_wallet, &store_keypair).await;
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
 
         let market_keypair = Keypair::new();
 
@@ -753,6 +1072,7 @@ mod create_market {
         let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
             &treasury_mint_keypair.pubkey(),
             &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
         );
 
         let treasury_holder_keypair = Keypair::new();
@@ -800,6 +1120,10 @@ mod create_market {
             pieces_in_one_wallet,
             start_date,
             end_date: Some(end_date),
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
         }
         .data();
 
@@ -828,4 +1152,87 @@ mod create_market {
             _ => assert!(false),
         }
     }
+
+    #[tokio::test]
+    async fn failure_vesting_starts_before_market() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let start_date = Utc::now().timestamp() as u64 + 3600;
+
+        // Vesting started before the sale would unlock funds right after purchase
+        let mut data = market_args(start_date);
+        data._treasyry_owner_bump = treasyry_owner_bump;
+        data.vesting = Some(Vesting {
+            start_date: start_date - 60,
+            cliff_duration: 1_000,
+            duration: 2_000,
+        });
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data: data.data(),
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::InvalidVesting);
+    }
 }
@@ -0,0 +1,265 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod finalize_market {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{assert_error, create_mint, create_token_account, get_account_data, wait},
+            setup_functions::{
+                buy, finalize_market, market_args, setup_buyer, setup_market,
+                setup_market_with_args, setup_selling_resource, setup_store,
+            },
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{Market, MarketState},
+        utils::find_treasury_owner_address,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::Instruction, signature::Keypair, signer::Signer, system_program, sysvar,
+        transaction::Transaction,
+    };
+    use std::time::SystemTime;
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = std::time::SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 5;
+
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasury_owner_bump,
+            name: "Marktname".to_string(),
+            description: "Marktbeschreibung".to_string(),
+            mutable: true,
+            price: 1_000_000,
+            pieces_in_one_wallet: Some(1),
+            start_date,
+            end_date: Some(start_date + 10),
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: None,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        wait(&mut context, Duration::seconds(1)).await;
+
+        // Anyone could finalize ended `Market`
+        let accounts = mpl_membership_token_accounts::FinalizeMarket {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            authority: context.payer.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::FinalizeMarket {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        assert_eq!(MarketState::Ended, market_data.state);
+        assert_eq!(Some(0), market_data.final_supply);
+    }
+
+    #[tokio::test]
+    async fn failure_market_is_not_ended() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::FinalizeMarket {
+            market: market_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            authority: context.payer.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::FinalizeMarket {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsNotEnded);
+    }
+
+    #[tokio::test]
+    async fn success_sold_out_by_owner() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let start_date = Utc::now().timestamp() as u64;
+        let mut args = market_args(start_date);
+        args.end_date = Some(start_date + 1_000_000);
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Sold out `Market` could not be finalized by anyone before `end_date`
+        let err = finalize_market(&mut context, &market_keypair.pubkey(), &user_wallet)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsNotEnded);
+
+        finalize_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        let market_data = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+
+        assert_eq!(MarketState::Ended, market_data.state);
+        assert_eq!(Some(1), market_data.final_supply);
+    }
+}
@@ -0,0 +1,243 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod increase_max_supply {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{assert_error, get_account_data, wait},
+            setup_functions::{
+                buy, market_args, setup_buyer, setup_market, setup_market_with_args,
+                setup_selling_resource, setup_selling_resource_with_master_edition, setup_store,
+            },
+        },
+    };
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts, error::ErrorCode,
+        instruction as mpl_membership_token_instruction, state::SellingResource,
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer, sysvar,
+        transaction::Transaction, transport::TransportError,
+    };
+
+    async fn increase_max_supply(
+        context: &mut ProgramTestContext,
+        market: &Pubkey,
+        selling_resource: &Pubkey,
+        owner: &Keypair,
+        selling_resource_owner: &Keypair,
+        max_supply: u64,
+    ) -> Result<(), TransportError> {
+        let selling_resource_data =
+            get_account_data::<SellingResource>(context, selling_resource).await;
+
+        let (master_edition, _) = Pubkey::find_program_address(
+            &[
+                mpl_token_metadata::state::PREFIX.as_bytes(),
+                mpl_token_metadata::id().as_ref(),
+                selling_resource_data.resource.as_ref(),
+                mpl_token_metadata::state::EDITION.as_bytes(),
+            ],
+            &mpl_token_metadata::id(),
+        );
+
+        let accounts = mpl_membership_token_accounts::IncreaseMaxSupply {
+            market: *market,
+            owner: owner.pubkey(),
+            selling_resource: *selling_resource,
+            selling_resource_owner: selling_resource_owner.pubkey(),
+            master_edition,
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::IncreaseMaxSupply { max_supply }.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let mut signers = vec![&context.payer, owner];
+        if selling_resource_owner.pubkey() != owner.pubkey() {
+            signers.push(selling_resource_owner);
+        }
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &signers,
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await
+    }
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        // `MasterEdition` is unlimited, while `SellingResource` is limited to one piece
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource_with_master_edition(
+                &mut context,
+                &admin_wallet,
+                &store_keypair,
+                None,
+                Some(1),
+            )
+            .await;
+
+        let mut args = market_args(Utc::now().timestamp() as u64);
+        args.pieces_in_one_wallet = None;
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        let (user_wallet, user_token_account) =
+            setup_buyer(&mut context, &admin_wallet, &market_keypair.pubkey()).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        // Old cap is reached
+        let err = buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::SupplyIsGtThanMaxSupply);
+
+        increase_max_supply(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            &selling_resource_owner_keypair,
+            2,
+        )
+        .await
+        .unwrap();
+
+        let selling_resource =
+            get_account_data::<SellingResource>(&mut context, &selling_resource_keypair.pubkey())
+                .await;
+
+        assert_eq!(Some(2), selling_resource.max_supply);
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_token_account.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let selling_resource =
+            get_account_data::<SellingResource>(&mut context, &selling_resource_keypair.pubkey())
+                .await;
+
+        assert_eq!(2, selling_resource.supply);
+    }
+
+    #[tokio::test]
+    async fn failure_signer_is_not_selling_resource_owner() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource_with_master_edition(
+                &mut context,
+                &admin_wallet,
+                &store_keypair,
+                None,
+                Some(1),
+            )
+            .await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let fake_selling_resource_owner = Keypair::new();
+
+        let err = increase_max_supply(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            &fake_selling_resource_owner,
+            2,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::PublicKeyMismatch);
+    }
+
+    #[tokio::test]
+    async fn failure_supply_is_gt_than_available() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        // `MasterEdition` max supply is equal to the `SellingResource` one
+        let err = increase_max_supply(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            &selling_resource_owner_keypair,
+            2,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::SupplyIsGtThanAvailable);
+    }
+}
@@ -0,0 +1,195 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod resume_market {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::assert_error,
+            setup_functions::{
+                finalize_market, resume_market, setup_market, setup_selling_resource, setup_store,
+                suspend_market,
+            },
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{Market, MarketState},
+    };
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::Instruction, signer::Signer, sysvar, transaction::Transaction,
+        transport::TransportError,
+    };
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        // SuspendMarket
+        let accounts = mpl_membership_token_accounts::SuspendMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::SuspendMarket {}.data();
+
+        let suspend_instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        // ResumeMarket
+        let accounts = mpl_membership_token_accounts::ResumeMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ResumeMarket {}.data();
+
+        let resume_instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[suspend_instruction, resume_instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        let market_data = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        // `Market` is not started yet
+        assert_eq!(MarketState::Created, market_data.state);
+    }
+
+    #[tokio::test]
+    async fn failure_market_is_not_suspended() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::ResumeMarket {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            clock: sysvar::clock::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::ResumeMarket {}.data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_market_is_finalized() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        suspend_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        finalize_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap();
+
+        // Finalized `Market` could not be reopened
+        let err = resume_market(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+        )
+        .await
+        .unwrap_err();
+
+        assert_error(err, ErrorCode::MarketIsEnded);
+    }
+}
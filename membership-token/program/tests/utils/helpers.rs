@@ -4,10 +4,21 @@ use anchor_client::solana_sdk::{
     pubkey::Pubkey,
     signer::{keypair::Keypair, Signer},
 };
+use anchor_lang::{AccountDeserialize, AnchorDeserialize, Discriminator};
 use chrono::{Duration, Utc};
-use solana_program::{clock::Clock, system_instruction};
+use lazy_static::lazy_static;
+use mpl_membership_token::error::ErrorCode;
+use solana_program::{
+    clock::Clock, instruction::InstructionError, keccak, program_error::ProgramError,
+    system_instruction,
+};
 use solana_program_test::*;
-use solana_sdk::{program_pack::Pack, transaction::Transaction};
+use solana_sdk::{
+    program_pack::Pack,
+    transaction::{Transaction, TransactionError},
+    transport::TransportError,
+};
+use std::sync::Mutex;
 
 pub async fn mint_to(
     context: &mut ProgramTestContext,
@@ -221,3 +232,143 @@ pub async fn airdrop(context: &mut ProgramTestContext, receiver: &Pubkey, amount
 
     context.banks_client.process_transaction(tx).await.unwrap();
 }
+
+/// Check, that transaction is failed with `mpl_membership_token` program `error`
+pub fn assert_error(err: TransportError, error: ErrorCode) {
+    let expected = match ProgramError::from(error) {
+        ProgramError::Custom(code) => code,
+        _ => unreachable!(),
+    };
+
+    match err {
+        TransportError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => assert_eq!(expected, code),
+        _ => panic!("Unexpected error: {:?}", err),
+    }
+}
+
+/// Fetch and deserialize program account
+pub async fn get_account_data<T: AccountDeserialize>(
+    context: &mut ProgramTestContext,
+    address: &Pubkey,
+) -> T {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    T::try_deserialize(&mut account.data.as_ref()).unwrap()
+}
+
+/// Return SPL token account balance
+pub async fn get_token_balance(context: &mut ProgramTestContext, address: &Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("account not found")
+        .expect("account empty");
+
+    spl_token::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+/// Return allowlist Merkle tree leaf for `wallet`, which is allowed to buy `amount` pieces
+pub fn allowlist_leaf(wallet: &Pubkey, amount: u64) -> [u8; 32] {
+    keccak::hashv(&[&[0x00], &wallet.to_bytes(), &amount.to_le_bytes()]).0
+}
+
+/// Build allowlist Merkle tree from `leaves` and return its root and proof for each leaf.
+/// Number of leaves should be a power of two
+pub fn allowlist_tree(leaves: &[[u8; 32]]) -> ([u8; 32], Vec<Vec<[u8; 32]>>) {
+    let mut proofs = vec![Vec::new(); leaves.len()];
+    let mut level = leaves.to_vec();
+    let mut depth = 0;
+
+    while level.len() > 1 {
+        for (i, proof) in proofs.iter_mut().enumerate() {
+            proof.push(level[(i >> depth) ^ 1]);
+        }
+
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let (left, right) = if pair[0] <= pair[1] {
+                    (pair[0], pair[1])
+                } else {
+                    (pair[1], pair[0])
+                };
+
+                keccak::hashv(&[&[0x01], &left, &right]).0
+            })
+            .collect();
+        depth += 1;
+    }
+
+    (level[0], proofs)
+}
+
+/// Target of runtime logger, which is used for program logs
+const PROGRAM_LOG_TARGET: &str = "solana_runtime::message_processor";
+
+lazy_static! {
+    static ref PROGRAM_LOGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+}
+
+/// Logger, which collects program logs to decode emitted events.
+/// Records are printed to stdout as well, like the `ProgramTest` default logger does
+struct ProgramLogCollector;
+
+impl log::Log for ProgramLogCollector {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target().starts_with(PROGRAM_LOG_TARGET) || metadata.level() <= log::Level::Info
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        println!("[{} {}] {}", record.level(), record.target(), record.args());
+
+        if record.target().starts_with(PROGRAM_LOG_TARGET) {
+            PROGRAM_LOGS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static PROGRAM_LOG_COLLECTOR: ProgramLogCollector = ProgramLogCollector;
+
+/// Install program log collector. Should be called before `ProgramTest` creation,
+/// otherwise `ProgramTest` installs its own global logger
+pub fn setup_log_collector() {
+    if log::set_logger(&PROGRAM_LOG_COLLECTOR).is_ok() {
+        log::set_max_level(log::LevelFilter::Debug);
+    }
+}
+
+/// Return all `T` events emitted so far. Logs are shared between tests running in parallel,
+/// so events should be filtered by their content
+pub fn get_events<T: AnchorDeserialize + Discriminator>() -> Vec<T> {
+    PROGRAM_LOGS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter_map(|log| {
+            let data = base64::decode(log.split("Program log: ").nth(1)?).ok()?;
+
+            if data.len() < 8 || data[..8] != T::discriminator() {
+                return None;
+            }
+
+            T::try_from_slice(&data[8..]).ok()
+        })
+        .collect()
+}
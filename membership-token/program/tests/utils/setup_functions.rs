@@ -2,18 +2,24 @@
 
 use super::helpers::{
     airdrop, create_master_edition, create_mint, create_token_account, create_token_metadata,
-    mint_to,
+    get_account_data, mint_to,
 };
 use anchor_lang::{InstructionData, ToAccountMetas};
 use chrono::NaiveDate;
 use mpl_membership_token::{
     accounts as mpl_membership_token_accounts, instruction as mpl_membership_token_instruction,
-    utils::{find_treasury_owner_address, find_vault_owner_address},
+    state::{AllowlistProof, Market, SellingResource},
+    utils::{find_trade_history_address, find_treasury_owner_address, find_vault_owner_address},
 };
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
-    instruction::Instruction, signature::Keypair, signer::Signer, system_program, sysvar,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program, sysvar,
     transaction::Transaction,
+    transport::TransportError,
 };
 use std::time::SystemTime;
 
@@ -21,6 +27,7 @@ use std::time::SystemTime;
 #[macro_export]
 macro_rules! setup_context {
     ( $context:ident, $( $program_name:ident ),+ ) => {
+        crate::utils::helpers::setup_log_collector();
         let mut program_test = ProgramTest::default();
         $(
             program_test.add_program(stringify!($program_name), $program_name::id(), None);
@@ -75,6 +82,25 @@ pub async fn setup_selling_resource(
     context: &mut ProgramTestContext,
     admin_wallet: &Keypair,
     store_keypair: &Keypair,
+    max_supply: Option<u64>,
+) -> (Keypair, Keypair, Keypair) {
+    setup_selling_resource_with_master_edition(
+        context,
+        admin_wallet,
+        store_keypair,
+        max_supply,
+        max_supply,
+    )
+    .await
+}
+
+/// Setup selling resource, which `MasterEdition` max supply differs from the `SellingResource` one
+pub async fn setup_selling_resource_with_master_edition(
+    context: &mut ProgramTestContext,
+    admin_wallet: &Keypair,
+    store_keypair: &Keypair,
+    master_edition_max_supply: Option<u64>,
+    max_supply: Option<u64>,
 ) -> (Keypair, Keypair, Keypair) {
     // Create `SellingResource`
     let resource_mint = Keypair::new();
@@ -124,7 +150,7 @@ pub async fn setup_selling_resource(
         &resource_mint.pubkey(),
         &admin_wallet,
         &metadata,
-        Some(1),
+        master_edition_max_supply,
     )
     .await;
 
@@ -157,7 +183,7 @@ pub async fn setup_selling_resource(
     let data = mpl_membership_token_instruction::InitSellingResource {
         _master_edition_bump: master_edition_bump,
         _vault_owner_bump: vault_owner_bump,
-        max_supply: Some(1),
+        max_supply,
     }
     .data();
 
@@ -189,6 +215,51 @@ pub async fn setup_market(
     store_keypair: &Keypair,
     selling_resource_keypair: &Keypair,
     selling_resource_owner_keypair: &Keypair,
+) -> Keypair {
+    let start_date = std::time::SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+        + 5;
+
+    setup_market_with_args(
+        context,
+        admin_wallet,
+        store_keypair,
+        selling_resource_keypair,
+        selling_resource_owner_keypair,
+        market_args(start_date),
+    )
+    .await
+}
+
+/// Default `CreateMarket` arguments
+pub fn market_args(start_date: u64) -> mpl_membership_token_instruction::CreateMarket {
+    mpl_membership_token_instruction::CreateMarket {
+        _treasyry_owner_bump: 0,
+        name: "Marktname".to_string(),
+        description: "Marktbeschreibung".to_string(),
+        mutable: true,
+        price: 1_000_000,
+        pieces_in_one_wallet: Some(1),
+        start_date,
+        end_date: None,
+        allowlist_root: None,
+        referral_fee_basis_points: 0,
+        dutch_auction: None,
+        vesting: None,
+    }
+}
+
+/// Setup `Market` with SPL token treasury and custom `CreateMarket` arguments.
+/// `_treasyry_owner_bump` is filled in automatically
+pub async fn setup_market_with_args(
+    context: &mut ProgramTestContext,
+    admin_wallet: &Keypair,
+    store_keypair: &Keypair,
+    selling_resource_keypair: &Keypair,
+    selling_resource_owner_keypair: &Keypair,
+    mut args: mpl_membership_token_instruction::CreateMarket,
 ) -> Keypair {
     let market_keypair = Keypair::new();
 
@@ -198,6 +269,7 @@ pub async fn setup_market(
     let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
         &treasury_mint_keypair.pubkey(),
         &selling_resource_keypair.pubkey(),
+        &market_keypair.pubkey(),
     );
 
     let treasury_holder_keypair = Keypair::new();
@@ -209,18 +281,6 @@ pub async fn setup_market(
     )
     .await;
 
-    let start_date = std::time::SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        + 5;
-
-    let name = "Marktname".to_string();
-    let description = "Marktbeschreibung".to_string();
-    let mutable = true;
-    let price = 1_000_000;
-    let pieces_in_one_wallet = Some(1);
-
     let accounts = mpl_membership_token_accounts::CreateMarket {
         market: market_keypair.pubkey(),
         store: store_keypair.pubkey(),
@@ -233,21 +293,66 @@ pub async fn setup_market(
     }
     .to_account_metas(None);
 
-    let data = mpl_membership_token_instruction::CreateMarket {
-        _treasyry_owner_bump: treasyry_owner_bump,
-        name: name.to_owned(),
-        description: description.to_owned(),
-        mutable,
-        price,
-        pieces_in_one_wallet,
-        start_date,
-        end_date: None,
+    args._treasyry_owner_bump = treasyry_owner_bump;
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data: args.data(),
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[
+            &context.payer,
+            &market_keypair,
+            selling_resource_owner_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    market_keypair
+}
+
+/// Setup `Market` with native SOL treasury and custom `CreateMarket` arguments.
+/// `_treasyry_owner_bump` is filled in automatically
+pub async fn setup_native_market_with_args(
+    context: &mut ProgramTestContext,
+    store_keypair: &Keypair,
+    selling_resource_keypair: &Keypair,
+    selling_resource_owner_keypair: &Keypair,
+    mut args: mpl_membership_token_instruction::CreateMarket,
+) -> Keypair {
+    let market_keypair = Keypair::new();
+
+    // Native SOL is collected directly by `treasury_owner`
+    let treasury_mint = spl_token::native_mint::id();
+    let (treasury_owner, treasyry_owner_bump) = find_treasury_owner_address(
+        &treasury_mint,
+        &selling_resource_keypair.pubkey(),
+        &market_keypair.pubkey(),
+    );
+
+    let accounts = mpl_membership_token_accounts::CreateMarket {
+        market: market_keypair.pubkey(),
+        store: store_keypair.pubkey(),
+        selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+        selling_resource: selling_resource_keypair.pubkey(),
+        mint: treasury_mint,
+        treasury_holder: treasury_owner,
+        owner: treasury_owner,
+        system_program: system_program::id(),
     }
-    .data();
+    .to_account_metas(None);
+
+    args._treasyry_owner_bump = treasyry_owner_bump;
 
     let instruction = Instruction {
         program_id: mpl_membership_token::id(),
-        data,
+        data: args.data(),
         accounts,
     };
 
@@ -257,7 +362,7 @@ pub async fn setup_market(
         &[
             &context.payer,
             &market_keypair,
-            &selling_resource_owner_keypair,
+            selling_resource_owner_keypair,
         ],
         context.last_blockhash,
     );
@@ -266,3 +371,428 @@ pub async fn setup_market(
 
     market_keypair
 }
+
+/// Setup buyer wallet and its treasury token account with `10_000_000` tokens.
+/// `admin_wallet` should be treasury mint authority, as in `setup_market_with_args`
+pub async fn setup_buyer(
+    context: &mut ProgramTestContext,
+    admin_wallet: &Keypair,
+    market: &Pubkey,
+) -> (Keypair, Keypair) {
+    let user_wallet = Keypair::new();
+    let user_token_account = fund_buyer(context, admin_wallet, market, &user_wallet).await;
+
+    (user_wallet, user_token_account)
+}
+
+/// Fund existing buyer wallet and create its treasury token account with `10_000_000` tokens
+pub async fn fund_buyer(
+    context: &mut ProgramTestContext,
+    admin_wallet: &Keypair,
+    market: &Pubkey,
+    user_wallet: &Keypair,
+) -> Keypair {
+    let market = get_account_data::<Market>(context, market).await;
+
+    airdrop(context, &user_wallet.pubkey(), 10_000_000_000).await;
+
+    let user_token_account = Keypair::new();
+    create_token_account(
+        context,
+        &user_token_account,
+        &market.treasury_mint,
+        &user_wallet.pubkey(),
+    )
+    .await;
+
+    mint_to(
+        context,
+        &market.treasury_mint,
+        &user_token_account.pubkey(),
+        admin_wallet,
+        10_000_000,
+    )
+    .await;
+
+    user_token_account
+}
+
+/// Buy one edition from `market` by `user_wallet`
+pub async fn buy(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    user_wallet: &Keypair,
+    user_token_account: &Pubkey,
+    referrer: Option<&Pubkey>,
+) -> Result<(), TransportError> {
+    buy_with_proof(
+        context,
+        market,
+        user_wallet,
+        user_token_account,
+        referrer,
+        None,
+    )
+    .await
+}
+
+/// Buy one edition from allowlist-gated `market` by `user_wallet`
+pub async fn buy_with_proof(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    user_wallet: &Keypair,
+    user_token_account: &Pubkey,
+    referrer: Option<&Pubkey>,
+    allowlist_proof: Option<AllowlistProof>,
+) -> Result<(), TransportError> {
+    let market_data = get_account_data::<Market>(context, market).await;
+    let selling_resource =
+        get_account_data::<SellingResource>(context, &market_data.selling_resource).await;
+
+    let (trade_history, trade_history_bump) =
+        find_trade_history_address(&user_wallet.pubkey(), market);
+    let (owner, vault_owner_bump) =
+        find_vault_owner_address(&selling_resource.resource, &selling_resource.store);
+
+    let new_mint_keypair = Keypair::new();
+    create_mint(context, &new_mint_keypair, &user_wallet.pubkey(), 0).await;
+
+    let new_mint_token_account = Keypair::new();
+    create_token_account(
+        context,
+        &new_mint_token_account,
+        &new_mint_keypair.pubkey(),
+        &user_wallet.pubkey(),
+    )
+    .await;
+
+    mint_to(
+        context,
+        &new_mint_keypair.pubkey(),
+        &new_mint_token_account.pubkey(),
+        user_wallet,
+        1,
+    )
+    .await;
+
+    let (master_edition_metadata, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            selling_resource.resource.as_ref(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let (master_edition, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            selling_resource.resource.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let (edition_marker, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            selling_resource.resource.as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+            (selling_resource.supply / mpl_token_metadata::state::EDITION_MARKER_BIT_SIZE)
+                .to_string()
+                .as_bytes(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let (new_metadata, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            new_mint_keypair.pubkey().as_ref(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let (new_edition, _) = Pubkey::find_program_address(
+        &[
+            mpl_token_metadata::state::PREFIX.as_bytes(),
+            mpl_token_metadata::id().as_ref(),
+            new_mint_keypair.pubkey().as_ref(),
+            mpl_token_metadata::state::EDITION.as_bytes(),
+        ],
+        &mpl_token_metadata::id(),
+    );
+
+    let mut accounts = mpl_membership_token_accounts::Buy {
+        market: *market,
+        selling_resource: market_data.selling_resource,
+        user_token_account: *user_token_account,
+        user_wallet: user_wallet.pubkey(),
+        trade_history,
+        treasury_holder: market_data.treasury_holder,
+        new_metadata,
+        new_edition,
+        master_edition,
+        new_mint: new_mint_keypair.pubkey(),
+        edition_marker,
+        vault: selling_resource.vault,
+        owner,
+        master_edition_metadata,
+        clock: sysvar::clock::id(),
+        rent: sysvar::rent::id(),
+        token_metadata_program: mpl_token_metadata::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    if let Some(referrer) = referrer {
+        accounts.push(AccountMeta::new(*referrer, false));
+    }
+
+    let data = mpl_membership_token_instruction::Buy {
+        _trade_history_bump: trade_history_bump,
+        vault_owner_bump,
+        allowlist_proof,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, user_wallet],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Suspend `market` by its `owner`
+pub async fn suspend_market(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    owner: &Keypair,
+) -> Result<(), TransportError> {
+    let accounts = mpl_membership_token_accounts::SuspendMarket {
+        market: *market,
+        owner: owner.pubkey(),
+        clock: sysvar::clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::SuspendMarket {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Resume suspended `market` by its `owner`
+pub async fn resume_market(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    owner: &Keypair,
+) -> Result<(), TransportError> {
+    let accounts = mpl_membership_token_accounts::ResumeMarket {
+        market: *market,
+        owner: owner.pubkey(),
+        clock: sysvar::clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::ResumeMarket {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Finalize `market` by `authority`
+pub async fn finalize_market(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    authority: &Keypair,
+) -> Result<(), TransportError> {
+    let market_data = get_account_data::<Market>(context, market).await;
+
+    let accounts = mpl_membership_token_accounts::FinalizeMarket {
+        market: *market,
+        selling_resource: market_data.selling_resource,
+        authority: authority.pubkey(),
+        clock: sysvar::clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::FinalizeMarket {}.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Withdraw unlocked treasury funds of `market` to `destination` by its `owner`
+pub async fn withdraw(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    owner: &Keypair,
+    destination: &Pubkey,
+) -> Result<(), TransportError> {
+    let market_data = get_account_data::<Market>(context, market).await;
+
+    let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+        &market_data.treasury_mint,
+        &market_data.selling_resource,
+        market,
+    );
+
+    let accounts = mpl_membership_token_accounts::Withdraw {
+        market: *market,
+        owner: owner.pubkey(),
+        treasury_holder: market_data.treasury_holder,
+        treasury_owner,
+        destination: *destination,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::Withdraw {
+        treasury_owner_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Close ended `market` by its `owner`
+pub async fn close_market(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    owner: &Keypair,
+) -> Result<(), TransportError> {
+    let market_data = get_account_data::<Market>(context, market).await;
+
+    let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+        &market_data.treasury_mint,
+        &market_data.selling_resource,
+        market,
+    );
+
+    let accounts = mpl_membership_token_accounts::CloseMarket {
+        market: *market,
+        owner: owner.pubkey(),
+        treasury_holder: market_data.treasury_holder,
+        treasury_owner,
+        clock: sysvar::clock::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::CloseMarket {
+        treasury_owner_bump,
+    }
+    .data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Approve `referrers` of `market` by its `owner`
+pub async fn change_referrers(
+    context: &mut ProgramTestContext,
+    market: &Pubkey,
+    owner: &Keypair,
+    referrers: Vec<Pubkey>,
+) -> Result<(), TransportError> {
+    let accounts = mpl_membership_token_accounts::ChangeReferrers {
+        market: *market,
+        owner: owner.pubkey(),
+        clock: sysvar::clock::id(),
+    }
+    .to_account_metas(None);
+
+    let data = mpl_membership_token_instruction::ChangeReferrers { referrers }.data();
+
+    let instruction = Instruction {
+        program_id: mpl_membership_token::id(),
+        data,
+        accounts,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, owner],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
@@ -0,0 +1,524 @@
+mod utils;
+
+#[cfg(feature = "test-bpf")]
+mod withdraw {
+    use crate::{
+        setup_context,
+        utils::{
+            helpers::{
+                airdrop, assert_error, create_mint, create_token_account, get_account_data,
+                get_token_balance, mint_to, wait,
+            },
+            setup_functions::{
+                buy, market_args, setup_market, setup_market_with_args,
+                setup_native_market_with_args, setup_selling_resource, setup_store, withdraw,
+            },
+        },
+    };
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use chrono::{Duration, Utc};
+    use mpl_membership_token::{
+        accounts as mpl_membership_token_accounts,
+        error::ErrorCode,
+        instruction as mpl_membership_token_instruction,
+        state::{Market, Vesting},
+        utils::find_treasury_owner_address,
+    };
+    use solana_program::clock::Clock;
+    use solana_program_test::*;
+    use solana_sdk::{
+        instruction::Instruction, program_pack::Pack, signature::Keypair, signer::Signer,
+        system_program, transaction::Transaction, transport::TransportError,
+    };
+    use std::time::SystemTime;
+
+    #[tokio::test]
+    async fn success() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        // Emulate collected funds
+        mint_to(
+            &mut context,
+            &market.treasury_mint,
+            &market.treasury_holder,
+            &admin_wallet,
+            1_000_000,
+        )
+        .await;
+
+        let destination = Keypair::new();
+        create_token_account(
+            &mut context,
+            &destination,
+            &market.treasury_mint,
+            &selling_resource_owner_keypair.pubkey(),
+        )
+        .await;
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &market.treasury_mint,
+            &market.selling_resource,
+            &market_keypair.pubkey(),
+        );
+
+        let accounts = mpl_membership_token_accounts::Withdraw {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            treasury_holder: market.treasury_holder,
+            treasury_owner,
+            destination: destination.pubkey(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::Withdraw {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let destination_acc = context
+            .banks_client
+            .get_account(destination.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let destination_data = spl_token::state::Account::unpack(&destination_acc.data).unwrap();
+
+        let treasury_holder_acc = context
+            .banks_client
+            .get_account(market.treasury_holder)
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let treasury_holder_data =
+            spl_token::state::Account::unpack(&treasury_holder_acc.data).unwrap();
+
+        assert_eq!(1_000_000, destination_data.amount);
+        assert_eq!(0, treasury_holder_data.amount);
+    }
+
+    #[tokio::test]
+    async fn success_native() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let args = market_args(Utc::now().timestamp() as u64);
+        let price = args.price;
+
+        let market_keypair = setup_native_market_with_args(
+            &mut context,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        wait(&mut context, Duration::seconds(2)).await;
+
+        // Native SOL `Market` does not use buyer token account
+        let user_wallet = Keypair::new();
+        airdrop(&mut context, &user_wallet.pubkey(), 10_000_000_000).await;
+
+        buy(
+            &mut context,
+            &market_keypair.pubkey(),
+            &user_wallet,
+            &user_wallet.pubkey(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let market = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market.data.as_ref()).unwrap();
+
+        let destination = Keypair::new();
+
+        withdraw(
+            &mut context,
+            &market_keypair.pubkey(),
+            &selling_resource_owner_keypair,
+            &destination.pubkey(),
+        )
+        .await
+        .unwrap();
+
+        let destination_acc = context
+            .banks_client
+            .get_account(destination.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let treasury_owner_acc = context
+            .banks_client
+            .get_account(market.treasury_owner)
+            .await
+            .expect("account not found")
+            .expect("account empty");
+
+        // Treasury is kept rent exempt after withdrawal
+        let rent = context.banks_client.get_rent().await.unwrap();
+
+        assert_eq!(price, destination_acc.lamports);
+        assert_eq!(rent.minimum_balance(0), treasury_owner_acc.lamports);
+    }
+
+    #[tokio::test]
+    async fn failure_signer_is_not_owner() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = setup_market(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+        )
+        .await;
+
+        let market_acc = context
+            .banks_client
+            .get_account(market_keypair.pubkey())
+            .await
+            .expect("account not found")
+            .expect("account empty");
+        let market = Market::try_deserialize(&mut market_acc.data.as_ref()).unwrap();
+
+        let fake_owner = Keypair::new();
+
+        let destination = Keypair::new();
+        create_token_account(
+            &mut context,
+            &destination,
+            &market.treasury_mint,
+            &fake_owner.pubkey(),
+        )
+        .await;
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &market.treasury_mint,
+            &market.selling_resource,
+            &market_keypair.pubkey(),
+        );
+
+        let accounts = mpl_membership_token_accounts::Withdraw {
+            market: market_keypair.pubkey(),
+            owner: fake_owner.pubkey(),
+            treasury_holder: market.treasury_holder,
+            treasury_owner,
+            destination: destination.pubkey(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::Withdraw {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &fake_owner],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        match err {
+            TransportError::Custom(_) => assert!(true),
+            TransportError::TransactionError(_) => assert!(true),
+            _ => assert!(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_treasury_is_locked() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let market_keypair = Keypair::new();
+
+        let treasury_mint_keypair = Keypair::new();
+        create_mint(
+            &mut context,
+            &treasury_mint_keypair,
+            &admin_wallet.pubkey(),
+            0,
+        )
+        .await;
+
+        let (treasury_owner, treasury_owner_bump) = find_treasury_owner_address(
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_keypair.pubkey(),
+            &market_keypair.pubkey(),
+        );
+
+        let treasury_holder_keypair = Keypair::new();
+        create_token_account(
+            &mut context,
+            &treasury_holder_keypair,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_owner,
+        )
+        .await;
+
+        let start_date = std::time::SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 5;
+
+        let accounts = mpl_membership_token_accounts::CreateMarket {
+            market: market_keypair.pubkey(),
+            store: store_keypair.pubkey(),
+            selling_resource_owner: selling_resource_owner_keypair.pubkey(),
+            selling_resource: selling_resource_keypair.pubkey(),
+            mint: treasury_mint_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            owner: treasury_owner,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::CreateMarket {
+            _treasyry_owner_bump: treasury_owner_bump,
+            name: "Marktname".to_string(),
+            description: "Marktbeschreibung".to_string(),
+            mutable: true,
+            price: 1_000_000,
+            pieces_in_one_wallet: Some(1),
+            start_date,
+            end_date: None,
+            allowlist_root: None,
+            referral_fee_basis_points: 0,
+            dutch_auction: None,
+            vesting: Some(Vesting {
+                start_date,
+                cliff_duration: 1_000,
+                duration: 2_000,
+            }),
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[
+                &context.payer,
+                &market_keypair,
+                &selling_resource_owner_keypair,
+            ],
+            context.last_blockhash,
+        );
+
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        // Emulate collected funds
+        mint_to(
+            &mut context,
+            &treasury_mint_keypair.pubkey(),
+            &treasury_holder_keypair.pubkey(),
+            &admin_wallet,
+            1_000_000,
+        )
+        .await;
+
+        let destination = Keypair::new();
+        create_token_account(
+            &mut context,
+            &destination,
+            &treasury_mint_keypair.pubkey(),
+            &selling_resource_owner_keypair.pubkey(),
+        )
+        .await;
+
+        let accounts = mpl_membership_token_accounts::Withdraw {
+            market: market_keypair.pubkey(),
+            owner: selling_resource_owner_keypair.pubkey(),
+            treasury_holder: treasury_holder_keypair.pubkey(),
+            treasury_owner,
+            destination: destination.pubkey(),
+            token_program: spl_token::id(),
+            system_program: system_program::id(),
+        }
+        .to_account_metas(None);
+
+        let data = mpl_membership_token_instruction::Withdraw {
+            treasury_owner_bump,
+        }
+        .data();
+
+        let instruction = Instruction {
+            program_id: mpl_membership_token::id(),
+            data,
+            accounts,
+        };
+
+        let tx = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &selling_resource_owner_keypair],
+            context.last_blockhash,
+        );
+
+        let err = context
+            .banks_client
+            .process_transaction(tx)
+            .await
+            .unwrap_err();
+
+        assert_error(err, ErrorCode::TreasuryIsLocked);
+    }
+
+    #[tokio::test]
+    async fn success_vesting_partial_unlock() {
+        setup_context!(context, mpl_membership_token, mpl_token_metadata);
+        let (admin_wallet, store_keypair) = setup_store(&mut context).await;
+
+        let (selling_resource_keypair, selling_resource_owner_keypair, _) =
+            setup_selling_resource(&mut context, &admin_wallet, &store_keypair, Some(1)).await;
+
+        let start_date = Utc::now().timestamp() as u64;
+        let vesting = Vesting {
+            start_date,
+            cliff_duration: 0,
+            duration: 1_000_000,
+        };
+
+        let mut args = market_args(start_date);
+        args.vesting = Some(vesting.clone());
+
+        let market_keypair = setup_market_with_args(
+            &mut context,
+            &admin_wallet,
+            &store_keypair,
+            &selling_resource_keypair,
+            &selling_resource_owner_keypair,
+            args,
+        )
+        .await;
+
+        let market = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+
+        // Emulate collected funds
+        let total = 1_000_000;
+        mint_to(
+            &mut context,
+            &market.treasury_mint,
+            &market.treasury_holder,
+            &admin_wallet,
+            total,
+        )
+        .await;
+
+        let destination = Keypair::new();
+        create_token_account(
+            &mut context,
+            &destination,
+            &market.treasury_mint,
+            &selling_resource_owner_keypair.pubkey(),
+        )
+        .await;
+
+        // Each withdrawal takes only the part unlocked since the previous one
+        for _ in 0..2 {
+            wait(&mut context, Duration::seconds(10)).await;
+
+            let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+            let unlocked = vesting.unlocked_amount(total, clock.unix_timestamp as u64);
+
+            withdraw(
+                &mut context,
+                &market_keypair.pubkey(),
+                &selling_resource_owner_keypair,
+                &destination.pubkey(),
+            )
+            .await
+            .unwrap();
+
+            let market = get_account_data::<Market>(&mut context, &market_keypair.pubkey()).await;
+
+            assert!(unlocked > 0 && unlocked < total);
+            assert_eq!(market.withdrawn, unlocked);
+            assert_eq!(
+                get_token_balance(&mut context, &destination.pubkey()).await,
+                unlocked
+            );
+            assert_eq!(
+                get_token_balance(&mut context, &market.treasury_holder).await,
+                total - unlocked
+            );
+        }
+    }
+}